@@ -1,6 +1,12 @@
 use chrono::{DateTime, Utc};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::cell::UnsafeCell;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Write};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 use thiserror::Error;
 
 /// A simple data structure that demonstrates serialization
@@ -18,6 +24,14 @@ pub enum LibraryError {
     InvalidValue(String),
     #[error("Serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
+    #[error("NDJSON parse error at line {line}: {source}")]
+    NdjsonParseError {
+        line: usize,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("I/O error: {0}")]
+    IoError(#[from] io::Error),
 }
 
 /// Add two numbers together
@@ -47,6 +61,76 @@ pub fn from_json(json: &str) -> Result<DataPoint, LibraryError> {
     Ok(serde_json::from_str(json)?)
 }
 
+/// Serialize a slice of data points as newline-delimited JSON (one `DataPoint` object per
+/// line), writing straight through to `writer` without buffering the whole stream.
+///
+/// Works equally for `DataPointSequence::current()` and `DoubleBuffer::front()`, since both
+/// expose the points to serialize as `&[DataPoint]`.
+pub fn to_ndjson<W: Write>(points: &[DataPoint], writer: &mut W) -> Result<(), LibraryError> {
+    for point in points {
+        let line = serde_json::to_string(point)?;
+        writeln!(writer, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// Lazily parse newline-delimited JSON `DataPoint` records from `reader`, one line at a time,
+/// so arbitrarily large streams never need to be fully buffered.
+///
+/// Blank lines are skipped. A malformed line surfaces as
+/// `LibraryError::NdjsonParseError` carrying its 1-based line number, rather than aborting
+/// the rest of the stream.
+pub fn from_ndjson<R: BufRead>(reader: R) -> impl Iterator<Item = Result<DataPoint, LibraryError>> {
+    reader.lines().enumerate().filter_map(|(index, line)| {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => return Some(Err(LibraryError::IoError(err))),
+        };
+        if line.trim().is_empty() {
+            return None;
+        }
+        Some(
+            serde_json::from_str(&line)
+                .map_err(|source| LibraryError::NdjsonParseError { line: index + 1, source }),
+        )
+    })
+}
+
+/// Common handle-addressed storage operations shared by pools that let a caller acquire
+/// space, read or modify it in place by handle, and release it back for reuse.
+///
+/// [`FlatObjectPool`] (a single growable buffer) and [`StaticMemoryPool`] (fixed
+/// segregated-size buckets) both implement this, so code that only needs
+/// acquire/read/modify/release can be generic over which storage strategy backs it: a
+/// growable heap buffer for servers, or a deterministic, pre-allocated set of size classes
+/// for embedded/`no_std`-style deployments.
+///
+/// [`ObjectPool`] deliberately does not implement this trait. It hands out an owned
+/// `Vec<T>` the caller keeps and later returns outright, rather than addressing into
+/// storage the pool itself still owns; forcing that onto a read/modify-by-handle contract
+/// would misrepresent its ownership-transfer model rather than unify it.
+pub trait PoolProvider<T> {
+    /// Handle returned by `acquire`, opaque to callers, passed back to `read`/`modify`/`release`.
+    type Handle;
+    /// Error returned when `acquire` cannot satisfy a request.
+    type Error;
+
+    /// Copy `data` into newly acquired storage and return a handle to it.
+    fn acquire(&mut self, data: &[T]) -> Result<Self::Handle, Self::Error>;
+
+    /// Copy the record at `handle` into `out`.
+    fn read(&self, handle: &Self::Handle, out: &mut [T]) -> Result<(), Self::Error>;
+
+    /// Run `f` against the record at `handle` in place.
+    fn modify(&mut self, handle: &Self::Handle, f: impl FnOnce(&mut [T]));
+
+    /// Release the storage at `handle` back to the pool for reuse.
+    fn release(&mut self, handle: Self::Handle);
+
+    /// Get the amount of free storage currently available to acquire into.
+    fn available_count(&self) -> usize;
+}
+
 /// Flat object pool using a single contiguous buffer with begin/end pointers.
 ///
 /// This structure uses a single flat `Vec<T>` as backing storage and tracks
@@ -54,6 +138,12 @@ pub fn from_json(json: &str) -> Result<DataPoint, LibraryError> {
 /// cache locality and reduces memory fragmentation compared to storing multiple
 /// separate vectors.
 ///
+/// The free list is kept sorted by `begin` and coalesced on every release: adjacent free
+/// ranges are merged into one, and a free range reaching all the way to the end of the
+/// buffer is truncated away instead of sitting there unused. `acquire` picks the smallest
+/// free range that still fits the request (best-fit), splitting off any remainder, which
+/// keeps fragmentation down compared to first-fit.
+///
 /// # Examples
 ///
 /// ```
@@ -75,8 +165,7 @@ pub fn from_json(json: &str) -> Result<DataPoint, LibraryError> {
 #[derive(Debug)]
 pub struct FlatObjectPool<T> {
     buffer: Vec<T>,
-    free_ranges: Vec<(usize, usize)>, // (begin, end) pairs
-    capacity: usize,
+    free_ranges: Vec<(usize, usize)>, // (begin, end) pairs, sorted by `begin`, non-adjacent
 }
 
 impl<T: Default + Clone> FlatObjectPool<T> {
@@ -84,41 +173,40 @@ impl<T: Default + Clone> FlatObjectPool<T> {
     ///
     /// # Arguments
     /// * `buffer_size` - Total size of the backing buffer
-    /// * `capacity` - Maximum number of free ranges to track
+    /// * `capacity` - Initial reserve for the number of free ranges expected to be tracked
     pub fn new(buffer_size: usize, capacity: usize) -> Self {
         Self {
             buffer: vec![T::default(); buffer_size],
             free_ranges: Vec::with_capacity(capacity),
-            capacity,
         }
     }
 
     /// Acquire a slice of the specified size from the pool.
     ///
-    /// Returns (begin, end) indices for the acquired slice.
-    /// If no suitable range is available, extends the buffer.
+    /// Returns (begin, end) indices for the acquired slice. Uses best-fit: the smallest free
+    /// range that is still large enough, splitting off any remainder back into the free list.
+    /// If no free range is large enough, extends the buffer.
     pub fn acquire(&mut self, size: usize) -> (usize, usize) {
-        // Try to find a free range that fits
-        for i in 0..self.free_ranges.len() {
-            let (begin, end) = self.free_ranges[i];
+        let mut best: Option<(usize, usize)> = None; // (free_ranges index, range size)
+        for (i, &(begin, end)) in self.free_ranges.iter().enumerate() {
             let range_size = end - begin;
-            
-            if range_size >= size {
-                // Use this range
-                self.free_ranges.remove(i);
-                
-                // If range is larger than needed, return the excess
-                if range_size > size {
-                    let new_begin = begin + size;
-                    if self.free_ranges.len() < self.capacity {
-                        self.free_ranges.push((new_begin, end));
-                    }
-                }
-                
-                return (begin, begin + size);
+            let is_better = match best {
+                Some((_, best_size)) => range_size >= size && range_size < best_size,
+                None => range_size >= size,
+            };
+            if is_better {
+                best = Some((i, range_size));
+            }
+        }
+
+        if let Some((i, range_size)) = best {
+            let (begin, end) = self.free_ranges.remove(i);
+            if range_size > size {
+                self.free_ranges.insert(i, (begin + size, end));
             }
+            return (begin, begin + size);
         }
-        
+
         // No suitable range found, extend buffer
         let begin = self.buffer.len();
         let end = begin + size;
@@ -128,23 +216,47 @@ impl<T: Default + Clone> FlatObjectPool<T> {
 
     /// Release a slice back to the pool for reuse.
     ///
-    /// The slice data is cleared and the range is added to the free list.
+    /// The slice data is cleared, then the range is inserted into the free list in sorted
+    /// position and coalesced with an adjacent previous and/or next range. If the resulting
+    /// range reaches the end of the buffer, the buffer is truncated and the range is dropped
+    /// instead of being tracked, so released tail memory is actually returned.
     pub fn release(&mut self, begin: usize, end: usize) {
         if begin >= end || end > self.buffer.len() {
             return; // Invalid range
         }
-        
-        // Clear the range
+
         for i in begin..end {
             self.buffer[i] = T::default();
         }
-        
-        // Add to free ranges if capacity allows
-        if self.free_ranges.len() < self.capacity {
-            self.free_ranges.push((begin, end));
+
+        let mut merged_begin = begin;
+        let mut merged_end = end;
+        let mut pos = self.free_ranges.partition_point(|&(b, _)| b < begin);
+
+        if pos > 0 && self.free_ranges[pos - 1].1 == merged_begin {
+            merged_begin = self.free_ranges[pos - 1].0;
+            self.free_ranges.remove(pos - 1);
+            pos -= 1;
+        }
+        if pos < self.free_ranges.len() && self.free_ranges[pos].0 == merged_end {
+            merged_end = self.free_ranges[pos].1;
+            self.free_ranges.remove(pos);
+        }
+        self.free_ranges.insert(pos, (merged_begin, merged_end));
+
+        if let Some(&(tail_begin, tail_end)) = self.free_ranges.last() {
+            if tail_end == self.buffer.len() {
+                self.free_ranges.pop();
+                self.buffer.truncate(tail_begin);
+            }
         }
     }
 
+    /// Get the total number of bytes (elements) currently free across all tracked ranges.
+    pub fn available_bytes(&self) -> usize {
+        self.free_ranges.iter().map(|&(b, e)| e - b).sum()
+    }
+
     /// Get a reference to an element in the buffer
     pub fn get(&self, index: usize) -> Option<&T> {
         self.buffer.get(index)
@@ -184,6 +296,38 @@ impl<T: Default + Clone> FlatObjectPool<T> {
     }
 }
 
+impl<T: Default + Clone> PoolProvider<T> for FlatObjectPool<T> {
+    type Handle = (usize, usize);
+    type Error = std::convert::Infallible;
+
+    fn acquire(&mut self, data: &[T]) -> Result<Self::Handle, Self::Error> {
+        let (begin, end) = FlatObjectPool::acquire(self, data.len());
+        self.get_slice_mut(begin, end).clone_from_slice(data);
+        Ok((begin, end))
+    }
+
+    fn read(&self, handle: &Self::Handle, out: &mut [T]) -> Result<(), Self::Error> {
+        let (begin, end) = *handle;
+        let len = out.len().min(end - begin);
+        out[..len].clone_from_slice(self.get_slice(begin, begin + len));
+        Ok(())
+    }
+
+    fn modify(&mut self, handle: &Self::Handle, f: impl FnOnce(&mut [T])) {
+        let (begin, end) = *handle;
+        f(self.get_slice_mut(begin, end));
+    }
+
+    fn release(&mut self, handle: Self::Handle) {
+        let (begin, end) = handle;
+        FlatObjectPool::release(self, begin, end);
+    }
+
+    fn available_count(&self) -> usize {
+        self.available_bytes()
+    }
+}
+
 /// Object pool for managing reusable vector objects.
 ///
 /// This structure maintains a pool of pre-allocated vectors that can be reused,
@@ -212,6 +356,7 @@ impl<T: Default + Clone> FlatObjectPool<T> {
 pub struct ObjectPool<T> {
     available: Vec<Vec<T>>,
     capacity: usize,
+    shrink_to: Option<usize>,
 }
 
 impl<T> ObjectPool<T> {
@@ -220,6 +365,21 @@ impl<T> ObjectPool<T> {
         Self {
             available: Vec::with_capacity(capacity),
             capacity,
+            shrink_to: None,
+        }
+    }
+
+    /// Create a new object pool that caps the capacity of every pooled vector.
+    ///
+    /// On `release`, if a vector's capacity exceeds `shrink_to`, it is shrunk down to
+    /// `shrink_to` before being re-pooled. This bounds worst-case memory at
+    /// `capacity * shrink_to` instead of letting a single large burst permanently inflate
+    /// every vector the pool ever hands out again.
+    pub fn with_shrink(capacity: usize, shrink_to: usize) -> Self {
+        Self {
+            available: Vec::with_capacity(capacity),
+            capacity,
+            shrink_to: Some(shrink_to),
         }
     }
 
@@ -233,11 +393,18 @@ impl<T> ObjectPool<T> {
 
     /// Return a vector to the pool for reuse.
     ///
-    /// The vector will be cleared before being added to the pool.
-    /// If the pool is at capacity, the vector will be dropped instead.
+    /// The vector will be cleared before being added to the pool. If a shrink threshold was
+    /// configured via [`ObjectPool::with_shrink`] and the vector's capacity exceeds it, the
+    /// vector is shrunk to that threshold first. If the pool is at capacity, the vector will
+    /// be dropped instead.
     pub fn release(&mut self, mut vec: Vec<T>) {
         if self.available.len() < self.capacity {
             vec.clear();
+            if let Some(shrink_to) = self.shrink_to {
+                if vec.capacity() > shrink_to {
+                    vec.shrink_to(shrink_to);
+                }
+            }
             self.available.push(vec);
         }
     }
@@ -246,6 +413,209 @@ impl<T> ObjectPool<T> {
     pub fn available_count(&self) -> usize {
         self.available.len()
     }
+
+    /// Acquire a vector from the pool wrapped in a [`PooledVec`] guard that automatically
+    /// clears and returns it to this pool when dropped.
+    ///
+    /// Unlike [`ObjectPool::acquire`], this is panic-safe: an early return, a `?`, or a panic
+    /// while the guard is in scope still returns the vector to the pool instead of leaking it
+    /// into general heap churn.
+    pub fn acquire_guarded(&mut self) -> PooledVec<'_, T> {
+        PooledVec {
+            vec: Some(self.acquire()),
+            pool: self,
+        }
+    }
+}
+
+/// RAII guard around a vector borrowed from an [`ObjectPool`].
+///
+/// Derefs to `Vec<T>` for normal use. On drop, the vector is cleared and pushed back into the
+/// pool it came from (or simply dropped if the pool is already at capacity), mirroring the
+/// borrow-a-block-return-on-drop pattern of byte-pool/kitsune's `PoolBuf`.
+///
+/// # Examples
+///
+/// ```
+/// use ry26::ObjectPool;
+///
+/// let mut pool: ObjectPool<i32> = ObjectPool::new(4);
+/// {
+///     let mut guard = pool.acquire_guarded();
+///     guard.push(1);
+///     guard.push(2);
+/// } // guard drops here, returning the (now-empty) vector to the pool
+///
+/// assert_eq!(pool.available_count(), 1);
+/// ```
+pub struct PooledVec<'a, T> {
+    pool: &'a mut ObjectPool<T>,
+    vec: Option<Vec<T>>,
+}
+
+impl<T> std::ops::Deref for PooledVec<'_, T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        self.vec.as_ref().expect("PooledVec's vector is only taken in Drop")
+    }
+}
+
+impl<T> std::ops::DerefMut for PooledVec<'_, T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        self.vec.as_mut().expect("PooledVec's vector is only taken in Drop")
+    }
+}
+
+impl<T> Drop for PooledVec<'_, T> {
+    fn drop(&mut self) {
+        if let Some(vec) = self.vec.take() {
+            self.pool.release(vec);
+        }
+    }
+}
+
+struct PoolNode<T> {
+    vec: Option<Vec<T>>,
+    next: usize,
+}
+
+/// Thread-safe counterpart to [`ObjectPool`] for multi-producer workloads.
+///
+/// The free list is a Treiber stack addressed by index into a fixed-size node slab, the same
+/// ABA-safe `(generation, index)`-packed `AtomicUsize` scheme as
+/// [`ConcurrentFlatObjectPool`]'s free-range stack. `acquire`/`release` take `&self`, so this
+/// pool is `Sync` and can be shared behind an `Arc` without an external lock. Once the slab is
+/// exhausted (more vectors released than `capacity`), `release` simply drops the vector instead
+/// of falling back to a mutex: unlike [`ConcurrentFlatObjectPool`], there is no shared buffer
+/// that a fallback path would need to coordinate growth against.
+///
+/// # Examples
+///
+/// ```
+/// use ry26::ConcurrentObjectPool;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let pool: Arc<ConcurrentObjectPool<i32>> = Arc::new(ConcurrentObjectPool::new(4));
+///
+/// let worker_pool = Arc::clone(&pool);
+/// let worker = thread::spawn(move || {
+///     let mut vec = worker_pool.acquire();
+///     vec.push(1);
+///     worker_pool.release(vec);
+/// });
+/// worker.join().unwrap();
+///
+/// assert_eq!(pool.available_count(), 1);
+/// ```
+#[derive(Debug)]
+pub struct ConcurrentObjectPool<T> {
+    nodes: Box<[UnsafeCell<PoolNode<T>>]>,
+    free_head: AtomicUsize,
+    spare_head: AtomicUsize,
+}
+
+// SAFETY: every node is only ever read or written while it is exclusively owned by whichever
+// thread just won the CAS that popped it off a stack, or by the thread about to push it (and
+// has not yet published it via CAS). No two threads ever touch the same node concurrently.
+unsafe impl<T: Send> Send for ConcurrentObjectPool<T> {}
+unsafe impl<T: Send> Sync for ConcurrentObjectPool<T> {}
+
+impl<T> ConcurrentObjectPool<T> {
+    /// Create a new concurrent object pool with the specified capacity.
+    pub fn new(capacity: usize) -> Self {
+        let nodes: Box<[UnsafeCell<PoolNode<T>>]> = (0..capacity)
+            .map(|i| {
+                let next = if i + 1 < capacity { i + 1 } else { NIL };
+                UnsafeCell::new(PoolNode { vec: None, next })
+            })
+            .collect();
+        let spare_head = if capacity == 0 {
+            pack_head(0, NIL)
+        } else {
+            pack_head(0, 0)
+        };
+        Self {
+            nodes,
+            free_head: AtomicUsize::new(pack_head(0, NIL)),
+            spare_head: AtomicUsize::new(spare_head),
+        }
+    }
+
+    fn pop(&self, head: &AtomicUsize) -> Option<usize> {
+        loop {
+            let word = head.load(Ordering::Acquire);
+            let (generation, index) = unpack_head(word);
+            if index == NIL {
+                return None;
+            }
+            let next = unsafe { (*self.nodes[index].get()).next };
+            let new_word = pack_head(generation.wrapping_add(1), next);
+            if head
+                .compare_exchange_weak(word, new_word, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return Some(index);
+            }
+        }
+    }
+
+    fn push(&self, head: &AtomicUsize, index: usize) {
+        loop {
+            let word = head.load(Ordering::Acquire);
+            let (generation, top) = unpack_head(word);
+            unsafe {
+                (*self.nodes[index].get()).next = top;
+            }
+            let new_word = pack_head(generation.wrapping_add(1), index);
+            if head
+                .compare_exchange_weak(word, new_word, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+
+    /// Acquire a vector from the pool, or create a new one if none available.
+    pub fn acquire(&self) -> Vec<T> {
+        if let Some(index) = self.pop(&self.free_head) {
+            let vec = unsafe { (*self.nodes[index].get()).vec.take() };
+            self.push(&self.spare_head, index);
+            vec.unwrap_or_default()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Return a vector to the pool for reuse.
+    ///
+    /// The vector is cleared before being stored. If every node slot is already holding a
+    /// released vector (the pool is at capacity), this vector is dropped instead.
+    pub fn release(&self, mut vec: Vec<T>) {
+        vec.clear();
+        if let Some(index) = self.pop(&self.spare_head) {
+            unsafe {
+                (*self.nodes[index].get()).vec = Some(vec);
+            }
+            self.push(&self.free_head, index);
+        }
+    }
+
+    /// Get the number of available vectors currently on the free-list stack.
+    ///
+    /// This walks the lock-free stack without synchronizing with concurrent pushes/pops, so
+    /// under contention it is a best-effort snapshot rather than an exact count.
+    pub fn available_count(&self) -> usize {
+        let mut count = 0;
+        let (_, mut index) = unpack_head(self.free_head.load(Ordering::Acquire));
+        while index != NIL {
+            count += 1;
+            index = unsafe { (*self.nodes[index].get()).next };
+        }
+        count
+    }
 }
 
 /// Double buffer for sequential updates of object vectors.
@@ -343,14 +713,248 @@ impl<T: Clone> DoubleBuffer<T> {
     }
 }
 
+impl<T: Clone> DoubleBuffer<T> {
+    /// Convert this double buffer into a wait-free, single-producer/single-consumer
+    /// triple-buffered handoff, returning `(Producer<T>, Consumer<T>)` handles that can run
+    /// on separate threads with no locks.
+    ///
+    /// Unlike [`DoubleBuffer::swap`], which needs `&mut self` around the whole buffer, the
+    /// returned [`Producer`] and [`Consumer`] only ever touch their own owned slot plus a CAS
+    /// on the shared middle-slot index, so a real producer and consumer thread never contend
+    /// on a lock. This consumes the double buffer, seeding the producer's slot from `back`
+    /// and the consumer's slot from `front`.
+    pub fn into_spsc(self) -> (Producer<T>, Consumer<T>) {
+        let slots = Arc::new(TripleSlots {
+            cells: [
+                UnsafeCell::new(self.back),
+                UnsafeCell::new(Vec::new()),
+                UnsafeCell::new(self.front),
+            ],
+        });
+        let shared = Arc::new(AtomicU8::new(1)); // middle = slot 1, not yet fresh
+
+        (
+            Producer {
+                slots: Arc::clone(&slots),
+                shared: Arc::clone(&shared),
+                owned: 0,
+            },
+            Consumer {
+                slots,
+                shared,
+                owned: 2,
+            },
+        )
+    }
+}
+
+const TRIPLE_INDEX_MASK: u8 = 0b011;
+const TRIPLE_FRESH_BIT: u8 = 0b100;
+
+struct TripleSlots<T> {
+    cells: [UnsafeCell<Vec<T>>; 3],
+}
+
+// SAFETY: each cell is only ever accessed by whichever handle (producer,
+// consumer, or neither while "in flight" as the shared middle slot) currently
+// owns its index; ownership only changes via the CAS in `publish`/`consume`,
+// which the `Acquire`/`Release` orderings pair with to synchronize the handoff.
+unsafe impl<T: Send> Send for TripleSlots<T> {}
+unsafe impl<T: Send> Sync for TripleSlots<T> {}
+
+/// The producer half of a wait-free SPSC triple buffer created by [`DoubleBuffer::into_spsc`].
+///
+/// Write into [`Producer::back_mut`] and call [`Producer::publish`] to hand the filled slot
+/// off to the consumer; publishing never blocks and never allocates.
+///
+/// # Examples
+///
+/// ```
+/// use ry26::DoubleBuffer;
+/// use std::thread;
+///
+/// let buffer: DoubleBuffer<i32> = DoubleBuffer::new(4);
+/// let (mut producer, mut consumer) = buffer.into_spsc();
+///
+/// let writer = thread::spawn(move || {
+///     producer.back_mut().push(42);
+///     producer.publish();
+/// });
+/// writer.join().unwrap();
+///
+/// while !consumer.consume() {
+///     std::thread::yield_now();
+/// }
+/// assert_eq!(consumer.latest(), &[42]);
+/// ```
+pub struct Producer<T> {
+    slots: Arc<TripleSlots<T>>,
+    shared: Arc<AtomicU8>,
+    owned: u8,
+}
+
+// SAFETY: `Producer` only ever touches `self.owned`'s cell, and handoff of
+// cell ownership is synchronized through the shared `AtomicU8`.
+unsafe impl<T: Send> Send for Producer<T> {}
+
+impl<T> Producer<T> {
+    /// Get a mutable reference to the producer-owned slot to fill with new data.
+    pub fn back_mut(&mut self) -> &mut Vec<T> {
+        unsafe { &mut *self.slots.cells[self.owned as usize].get() }
+    }
+
+    /// Publish the producer-owned slot, atomically swapping it with the shared middle slot and
+    /// marking it fresh for the consumer. Wait-free: a single CAS loop, no locks.
+    pub fn publish(&mut self) {
+        let mut current = self.shared.load(Ordering::Acquire);
+        loop {
+            let new_word = self.owned | TRIPLE_FRESH_BIT;
+            match self.shared.compare_exchange_weak(
+                current,
+                new_word,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(actual) => current = actual,
+            }
+        }
+        self.owned = current & TRIPLE_INDEX_MASK;
+    }
+}
+
+/// The consumer half of a wait-free SPSC triple buffer created by [`DoubleBuffer::into_spsc`].
+///
+/// Call [`Consumer::consume`] to pick up the latest published slot (if any), then read it via
+/// [`Consumer::latest`]. If nothing new has been published, `consume` leaves the
+/// consumer-owned slot untouched so `latest` keeps showing the last delivered data.
+pub struct Consumer<T> {
+    slots: Arc<TripleSlots<T>>,
+    shared: Arc<AtomicU8>,
+    owned: u8,
+}
+
+// SAFETY: `Consumer` only ever touches `self.owned`'s cell, and handoff of
+// cell ownership is synchronized through the shared `AtomicU8`.
+unsafe impl<T: Send> Send for Consumer<T> {}
+
+impl<T> Consumer<T> {
+    /// Atomically pick up the latest published slot, if the producer has published since the
+    /// last call. Returns `true` if new data is now visible via [`Consumer::latest`].
+    pub fn consume(&mut self) -> bool {
+        let mut current = self.shared.load(Ordering::Acquire);
+        loop {
+            if current & TRIPLE_FRESH_BIT == 0 {
+                return false;
+            }
+            match self.shared.compare_exchange_weak(
+                current,
+                self.owned,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(actual) => current = actual,
+            }
+        }
+        self.owned = current & TRIPLE_INDEX_MASK;
+        true
+    }
+
+    /// Get a reference to the consumer-owned slot (the most recently delivered data).
+    pub fn latest(&self) -> &[T] {
+        unsafe { &*self.slots.cells[self.owned as usize].get() }
+    }
+}
+
+/// Thread-safe counterpart to [`DoubleBuffer`] for a single producer and many concurrent
+/// readers, built on [`ConcurrentObjectPool`] so retired front buffers are recycled instead of
+/// reallocated on every swap.
+///
+/// Readers call [`SyncDoubleBuffer::front`] to take a cheap `Arc` clone of the current front
+/// buffer; once cloned, they can read it for as long as they like without taking any further
+/// lock, even while the producer is busy filling and publishing a new one. This realizes the
+/// single-producer/concurrent-reader pattern that [`DoubleBuffer::swap`]'s docs describe, which
+/// its `&mut self` API cannot actually offer across threads.
+///
+/// # Examples
+///
+/// ```
+/// use ry26::SyncDoubleBuffer;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let buffer: Arc<SyncDoubleBuffer<i32>> = Arc::new(SyncDoubleBuffer::new(4));
+///
+/// buffer.with_back_mut(|back| back.push(42));
+/// buffer.swap();
+///
+/// let reader_buffer = Arc::clone(&buffer);
+/// let reader = thread::spawn(move || reader_buffer.front());
+/// assert_eq!(*reader.join().unwrap(), vec![42]);
+/// ```
+pub struct SyncDoubleBuffer<T> {
+    front: Mutex<Arc<Vec<T>>>,
+    back: Mutex<Vec<T>>,
+    pool: ConcurrentObjectPool<T>,
+}
+
+impl<T> SyncDoubleBuffer<T> {
+    /// Create a new sync double buffer with an object pool of the specified capacity.
+    pub fn new(pool_capacity: usize) -> Self {
+        Self {
+            front: Mutex::new(Arc::new(Vec::new())),
+            back: Mutex::new(Vec::new()),
+            pool: ConcurrentObjectPool::new(pool_capacity),
+        }
+    }
+
+    /// Get a cheap `Arc` clone of the current front buffer for lock-free reading.
+    pub fn front(&self) -> Arc<Vec<T>> {
+        Arc::clone(&self.front.lock().unwrap())
+    }
+
+    /// Run `f` against the back (write) buffer. Only the single producer should call this.
+    pub fn with_back_mut<R>(&self, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+        let mut back = self.back.lock().unwrap();
+        f(&mut back)
+    }
+
+    /// Publish the back buffer as the new front, acquiring a fresh vector from the pool to
+    /// become the new back buffer.
+    ///
+    /// The retired front is returned to the pool if no reader still holds a reference to it
+    /// (this call is the sole owner); otherwise it is simply dropped once the last reader
+    /// releases its `Arc`, since a released `Arc` can no longer be handed back to the pool.
+    pub fn swap(&self) {
+        let mut back = self.back.lock().unwrap();
+        let filled = std::mem::replace(&mut *back, self.pool.acquire());
+        let new_front = Arc::new(filled);
+        let old_front = std::mem::replace(&mut *self.front.lock().unwrap(), new_front);
+        if let Ok(vec) = Arc::try_unwrap(old_front) {
+            self.pool.release(vec);
+        }
+    }
+}
+
 /// A sequence of DataPoint objects that accumulates immutably over time.
 ///
-/// `DataPointSequence` uses a `FlatObjectPool` to manage an append-only sequence
-/// of data points. Objects are never erased within a step - they accumulate in the
-/// flat buffer, providing an immutable history of all data points added.
+/// `DataPointSequence` commits points in discrete steps: `add_point`/`add_points`
+/// queue pending points, and `update()` publishes them into a reference-counted
+/// committed buffer. That buffer is mutated in place (cheap: just the pending
+/// append, plus whatever the window evicts) unless a [`DataPointSequence::snapshot`]
+/// is still holding a reference to it, in which case `update()` clones it first via
+/// `Rc::make_mut` so the snapshot keeps reading the exact points it captured no
+/// matter what the live sequence does afterwards.
 ///
-/// Each step adds new data points to the sequence, and the sequence grows over time.
-/// The design uses begin/end pointers to track the current extent of the sequence.
+/// For long-running use, [`DataPointSequence::with_window`] opts into a bounded
+/// mode instead: the committed buffer is a ring (`VecDeque`), and each `update()`
+/// evicts the oldest points from its front in O(evicted) — no shifting the rest
+/// of the window down, the way a plain `Vec::drain` from the front would. Each
+/// `update()` then rejoins a wrapped ring into one contiguous run
+/// (`VecDeque::make_contiguous`) so `current()` can return a single slice, which
+/// does move elements. The default [`DataPointSequence::new`] constructor keeps
+/// the original, unbounded behavior.
 ///
 /// # Examples
 ///
@@ -381,24 +985,46 @@ impl<T: Clone> DoubleBuffer<T> {
 /// ```
 #[derive(Debug)]
 pub struct DataPointSequence {
-    pool: FlatObjectPool<DataPoint>,
-    current_end: usize,  // End of the current visible sequence
-    next_end: usize,     // End including pending additions
+    committed: Rc<VecDeque<DataPoint>>,
+    committed_steps: Rc<VecDeque<usize>>, // step at which each `committed` point became visible
+    pending: VecDeque<DataPoint>,
     step: usize,
+    window_len: Option<usize>,
 }
 
 impl DataPointSequence {
     /// Create a new DataPointSequence with the specified buffer size and pool capacity
     ///
     /// # Arguments
-    /// * `buffer_size` - Initial size of the backing buffer
-    /// * `pool_capacity` - Capacity for tracking free ranges (not typically used in append-only mode)
-    pub fn new(buffer_size: usize, pool_capacity: usize) -> Self {
+    /// * `buffer_size` - Initial capacity hint for the committed buffer
+    /// * `pool_capacity` - Unused; kept for API compatibility with earlier versions
+    pub fn new(buffer_size: usize, _pool_capacity: usize) -> Self {
+        Self {
+            committed: Rc::new(VecDeque::with_capacity(buffer_size)),
+            committed_steps: Rc::new(VecDeque::with_capacity(buffer_size)),
+            pending: VecDeque::new(),
+            step: 0,
+            window_len: None,
+        }
+    }
+
+    /// Create a new DataPointSequence in bounded, sliding-window mode.
+    ///
+    /// Each `update()` commits the pending additions and then evicts the
+    /// oldest points from the front of the ring so the committed set never
+    /// holds more than `window_len` points.
+    ///
+    /// # Arguments
+    /// * `buffer_size` - Initial capacity hint for the committed buffer
+    /// * `pool_capacity` - Unused; kept for API compatibility with earlier versions
+    /// * `window_len` - Maximum number of committed points retained at once
+    pub fn with_window(buffer_size: usize, _pool_capacity: usize, window_len: usize) -> Self {
         Self {
-            pool: FlatObjectPool::new(buffer_size, pool_capacity),
-            current_end: 0,
-            next_end: 0,
+            committed: Rc::new(VecDeque::with_capacity(buffer_size.min(window_len))),
+            committed_steps: Rc::new(VecDeque::with_capacity(buffer_size.min(window_len))),
+            pending: VecDeque::new(),
             step: 0,
+            window_len: Some(window_len),
         }
     }
 
@@ -409,73 +1035,892 @@ impl DataPointSequence {
 
     /// Add a data point to the next update
     ///
-    /// The data point is appended to the flat buffer and will become visible
-    /// after the next `update()` call.
+    /// The data point is queued and will become visible after the next `update()` call.
     pub fn add_point(&mut self, point: DataPoint) {
-        // The FlatObjectPool will automatically extend if needed
-        self.pool.set(self.next_end, point);
-        self.next_end += 1;
+        self.pending.push_back(point);
     }
 
     /// Add multiple data points to the next update
     pub fn add_points(&mut self, points: impl IntoIterator<Item = DataPoint>) {
-        for point in points {
-            self.add_point(point);
-        }
+        self.pending.extend(points);
     }
 
     /// Update the sequence by making pending additions visible and incrementing the step counter.
     ///
-    /// This makes all data points added since the last update visible in the current sequence.
-    /// Objects are never erased - the sequence grows over time.
+    /// This appends the pending points into the committed ring buffer in place and evicts the
+    /// oldest entries past `window_len`, if any, by popping them off the front one at a time —
+    /// O(evicted), not a shift of the whole remaining window. The buffer is shared via `Rc`, so
+    /// if a [`SequenceSnapshot`] is still holding a reference to it, `Rc::make_mut` transparently
+    /// clones it first — the snapshot keeps seeing its own, now-detached buffer rather than
+    /// having it mutated out from under it. With no snapshot outstanding, this is an in-place
+    /// append plus however many points the window evicts, followed by `make_contiguous`, which
+    /// moves elements whenever the ring has wrapped.
     pub fn update(&mut self) {
-        self.current_end = self.next_end;
         self.step += 1;
+        let step = self.step;
+        let pending_count = self.pending.len();
+
+        let points = Rc::make_mut(&mut self.committed);
+        points.append(&mut self.pending);
+
+        let steps = Rc::make_mut(&mut self.committed_steps);
+        steps.extend(std::iter::repeat_n(step, pending_count));
+
+        if let Some(window_len) = self.window_len {
+            while points.len() > window_len {
+                points.pop_front();
+                steps.pop_front();
+            }
+        }
+
+        // Keep the ring contiguous so `current()` can hand out a single slice.
+        points.make_contiguous();
     }
 
-    /// Get a reference to the current sequence (all accumulated data points)
+    /// Get a reference to the current sequence (all committed data points)
     ///
-    /// Returns a slice containing all data points from the beginning to the current end.
-    /// This includes all data points added in all previous steps.
+    /// Returns a slice containing all data points from the beginning to the current end
+    /// in unbounded mode, or the sliding window in chronological order in windowed mode.
     pub fn current(&self) -> &[DataPoint] {
-        if self.current_end > 0 {
-            self.pool.get_slice(0, self.current_end)
-        } else {
-            &[]
+        self.committed.as_slices().0
+    }
+
+    /// Iterate over the committed points in insertion order.
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, DataPoint> {
+        self.committed.iter()
+    }
+
+    /// Iterate over the committed points whose step falls within `steps`.
+    pub fn range(&self, steps: std::ops::Range<usize>) -> impl Iterator<Item = &DataPoint> + '_ {
+        self.committed
+            .iter()
+            .zip(self.committed_steps.iter())
+            .filter(move |(_, &point_step)| steps.contains(&point_step))
+            .map(|(point, _)| point)
+    }
+
+    /// Take an immutable, point-in-time [`SequenceSnapshot`] of the current committed points.
+    ///
+    /// The snapshot holds a reference-counted clone of the committed buffer, so it keeps
+    /// exposing these points even after later `update()`/`clear()` calls replace the live
+    /// sequence's buffer.
+    pub fn snapshot(&self) -> SequenceSnapshot {
+        SequenceSnapshot {
+            points: Rc::clone(&self.committed),
+            step: self.step,
         }
     }
 
-    /// Get the number of data points in the current sequence
+    /// Get the oldest point still in the current window (or the whole sequence, if unbounded)
+    pub fn oldest(&self) -> Option<&DataPoint> {
+        self.committed.front()
+    }
+
+    /// Get the most recently committed point
+    pub fn newest(&self) -> Option<&DataPoint> {
+        self.committed.back()
+    }
+
+    /// Get the number of data points in the current sequence (or window, if bounded)
     pub fn len(&self) -> usize {
-        self.current_end
+        self.committed.len()
     }
 
     /// Check if the current sequence is empty
     pub fn is_empty(&self) -> bool {
-        self.current_end == 0
+        self.committed.is_empty()
     }
 
     /// Get the number of data points added but not yet visible (pending update)
     pub fn pending_count(&self) -> usize {
-        self.next_end - self.current_end
+        self.pending.len()
     }
 
-    /// Get the total buffer capacity
+    /// Get the size of the current committed buffer
     pub fn buffer_size(&self) -> usize {
-        self.pool.buffer_size()
+        self.committed.len()
     }
 
     /// Reset the sequence, clearing all data
     ///
-    /// Note: This resets the sequence to empty but does not shrink the underlying buffer.
+    /// Note: This replaces the committed buffer with a fresh, empty one rather than mutating
+    /// it in place, so any outstanding [`SequenceSnapshot`] keeps seeing the pre-clear data.
     pub fn clear(&mut self) {
-        let end = self.next_end;
-        self.current_end = 0;
-        self.next_end = 0;
+        self.committed = Rc::new(VecDeque::new());
+        self.committed_steps = Rc::new(VecDeque::new());
+        self.pending.clear();
         self.step = 0;
-        // Clear the buffer content
-        for i in 0..end {
-            self.pool.set(i, DataPoint::default());
-        }
+    }
+}
+
+impl<'a> IntoIterator for &'a DataPointSequence {
+    type Item = &'a DataPoint;
+    type IntoIter = std::collections::vec_deque::Iter<'a, DataPoint>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.committed.iter()
+    }
+}
+
+/// An immutable, point-in-time view over a [`DataPointSequence`]'s committed points.
+///
+/// Cheap to create (a reference-counted clone of the committed buffer) and stable: holding
+/// this clone forces the next `DataPointSequence::update()` to detach the live sequence's
+/// buffer via `Rc::make_mut` (and `clear()` always swaps in a fresh buffer) rather than
+/// mutating the one this snapshot points at,
+/// so it keeps reading the points that were committed as of [`SequenceSnapshot::step`] no
+/// matter what the live sequence does afterwards.
+///
+/// # Examples
+///
+/// ```
+/// use ry26::{DataPointSequence, DataPoint};
+///
+/// let mut sequence = DataPointSequence::new(10, 4);
+/// sequence.add_point(DataPoint { id: 1, value: 1.0, timestamp: "t1".to_string() });
+/// sequence.update();
+///
+/// let snapshot = sequence.snapshot();
+///
+/// sequence.add_point(DataPoint { id: 2, value: 2.0, timestamp: "t2".to_string() });
+/// sequence.update();
+///
+/// // The snapshot is unaffected by the second update.
+/// assert_eq!(snapshot.len(), 1);
+/// assert_eq!(sequence.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SequenceSnapshot {
+    points: Rc<VecDeque<DataPoint>>,
+    step: usize,
+}
+
+impl SequenceSnapshot {
+    /// The `DataPointSequence::step()` this snapshot was taken at.
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// All points visible in this snapshot, in insertion order.
+    pub fn as_slice(&self) -> &[DataPoint] {
+        self.points.as_slices().0
+    }
+
+    /// Number of points in this snapshot.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Check if this snapshot is empty.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Iterate over the points in this snapshot, in insertion order.
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, DataPoint> {
+        self.points.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a SequenceSnapshot {
+    type Item = &'a DataPoint;
+    type IntoIter = std::collections::vec_deque::Iter<'a, DataPoint>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.points.iter()
+    }
+}
+
+// Node index bit-packing for the Treiber stacks behind `ConcurrentObjectPool` and
+// `ConcurrentFlatObjectPool`.
+//
+// The stack head is a single `AtomicUsize` storing `(generation << INDEX_BITS) | index`.
+// Every successful pop or push bumps the generation, so a CAS that raced with an
+// intervening pop-then-push of the same node index still fails instead of silently
+// "succeeding" against stale data (the ABA problem).
+const INDEX_BITS: u32 = 32;
+const INDEX_MASK: usize = (1usize << INDEX_BITS) - 1;
+const NIL: usize = INDEX_MASK;
+
+#[inline]
+fn pack_head(generation: usize, index: usize) -> usize {
+    (generation << INDEX_BITS) | (index & INDEX_MASK)
+}
+
+#[inline]
+fn unpack_head(word: usize) -> (usize, usize) {
+    (word >> INDEX_BITS, word & INDEX_MASK)
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct FreeNode {
+    begin: usize,
+    end: usize,
+    next: usize,
+}
+
+/// Lock-free, `Sync` variant of [`FlatObjectPool`] for sharing a flat buffer
+/// between a producer and a consumer thread without an external lock.
+///
+/// The free list of released `(begin, end)` ranges is a Treiber stack: each
+/// node lives in a fixed-size slab sized to `capacity` and is addressed by
+/// index rather than pointer, and the stack head packs `(generation, node
+/// index)` into one `AtomicUsize` so CAS fails on an ABA race instead of
+/// succeeding against stale data. A second Treiber stack of spare node slots
+/// lets released nodes be recycled instead of consumed once per `release`.
+///
+/// Because a Treiber stack only exposes its top, `acquire` can only reuse a
+/// released range that both fronts the stack and is large enough for the
+/// request (no first-fit scan across threads); otherwise it falls through to
+/// growing the buffer. Buffer growth still needs coordination between
+/// threads, so that path is guarded by a mutex.
+///
+/// # Examples
+///
+/// ```
+/// use ry26::ConcurrentFlatObjectPool;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let pool: Arc<ConcurrentFlatObjectPool<i32>> = Arc::new(ConcurrentFlatObjectPool::new(100, 8));
+///
+/// let producer = Arc::clone(&pool);
+/// let writer = thread::spawn(move || {
+///     let (begin, end) = producer.acquire(4);
+///     for i in begin..end {
+///         producer.set(i, i as i32);
+///     }
+///     (begin, end)
+/// });
+///
+/// let (begin, end) = writer.join().unwrap();
+/// assert_eq!(pool.get(begin), Some(begin as i32));
+/// pool.release(begin, end);
+/// ```
+#[derive(Debug)]
+pub struct ConcurrentFlatObjectPool<T> {
+    buffer: Mutex<Vec<T>>,
+    nodes: Box<[UnsafeCell<FreeNode>]>,
+    free_head: AtomicUsize,
+    spare_head: AtomicUsize,
+}
+
+// SAFETY: every node is only ever read or written while it is exclusively
+// owned by whichever thread just won the CAS that popped it off a stack, or
+// by the thread that is about to push it (and has not yet published it via
+// CAS). No two threads ever touch the same node concurrently.
+unsafe impl<T: Send> Send for ConcurrentFlatObjectPool<T> {}
+unsafe impl<T: Send> Sync for ConcurrentFlatObjectPool<T> {}
+
+impl<T: Default + Clone> ConcurrentFlatObjectPool<T> {
+    /// Create a new concurrent flat object pool with the specified buffer size and capacity.
+    ///
+    /// # Arguments
+    /// * `buffer_size` - Initial size of the backing buffer
+    /// * `capacity` - Maximum number of free ranges tracked at once (also the node slab size)
+    pub fn new(buffer_size: usize, capacity: usize) -> Self {
+        let nodes: Box<[UnsafeCell<FreeNode>]> = (0..capacity)
+            .map(|i| {
+                let next = if i + 1 < capacity { i + 1 } else { NIL };
+                UnsafeCell::new(FreeNode {
+                    begin: 0,
+                    end: 0,
+                    next,
+                })
+            })
+            .collect();
+        let spare_head = if capacity == 0 {
+            pack_head(0, NIL)
+        } else {
+            pack_head(0, 0)
+        };
+        Self {
+            buffer: Mutex::new(vec![T::default(); buffer_size]),
+            nodes,
+            free_head: AtomicUsize::new(pack_head(0, NIL)),
+            spare_head: AtomicUsize::new(spare_head),
+        }
+    }
+
+    fn pop(&self, head: &AtomicUsize) -> Option<usize> {
+        loop {
+            let word = head.load(Ordering::Acquire);
+            let (generation, index) = unpack_head(word);
+            if index == NIL {
+                return None;
+            }
+            let next = unsafe { (*self.nodes[index].get()).next };
+            let new_word = pack_head(generation.wrapping_add(1), next);
+            if head
+                .compare_exchange_weak(word, new_word, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return Some(index);
+            }
+        }
+    }
+
+    fn push(&self, head: &AtomicUsize, index: usize) {
+        loop {
+            let word = head.load(Ordering::Acquire);
+            let (generation, top) = unpack_head(word);
+            unsafe {
+                (*self.nodes[index].get()).next = top;
+            }
+            let new_word = pack_head(generation.wrapping_add(1), index);
+            if head
+                .compare_exchange_weak(word, new_word, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
+
+    fn push_free_range(&self, begin: usize, end: usize) {
+        if let Some(index) = self.pop(&self.spare_head) {
+            unsafe {
+                let node = &mut *self.nodes[index].get();
+                node.begin = begin;
+                node.end = end;
+            }
+            self.push(&self.free_head, index);
+        }
+        // No spare node slots left: the range is dropped and its space is
+        // not reused.
+    }
+
+    /// Acquire a slice of the specified size from the pool.
+    ///
+    /// Pops the top of the free-range stack if it exists and fits; otherwise
+    /// falls back to a mutex-guarded buffer grow, matching
+    /// [`FlatObjectPool::acquire`]'s "extend if nothing fits" behavior.
+    pub fn acquire(&self, size: usize) -> (usize, usize) {
+        if let Some(index) = self.pop(&self.free_head) {
+            let (begin, end) = unsafe {
+                let node = &*self.nodes[index].get();
+                (node.begin, node.end)
+            };
+            let range_size = end - begin;
+            if range_size >= size {
+                self.push(&self.spare_head, index);
+                if range_size > size {
+                    self.push_free_range(begin + size, end);
+                }
+                return (begin, begin + size);
+            }
+            // Doesn't fit; requeue it and fall back to growing the buffer.
+            self.push(&self.free_head, index);
+        }
+
+        let mut buffer = self.buffer.lock().unwrap();
+        let begin = buffer.len();
+        let end = begin + size;
+        buffer.resize(end, T::default());
+        (begin, end)
+    }
+
+    /// Release a slice back to the pool for reuse.
+    ///
+    /// The slice data is cleared and the range is pushed onto the free-range stack, or
+    /// dropped if every node slot is already in use.
+    pub fn release(&self, begin: usize, end: usize) {
+        if begin >= end {
+            return;
+        }
+        {
+            let mut buffer = self.buffer.lock().unwrap();
+            if end > buffer.len() {
+                return; // Invalid range
+            }
+            for slot in &mut buffer[begin..end] {
+                *slot = T::default();
+            }
+        }
+        self.push_free_range(begin, end);
+    }
+
+    /// Get a copy of the element at the specified index.
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.buffer.lock().unwrap().get(index).cloned()
+    }
+
+    /// Set the value at the specified index, extending the buffer if necessary.
+    pub fn set(&self, index: usize, value: T) {
+        let mut buffer = self.buffer.lock().unwrap();
+        if index >= buffer.len() {
+            buffer.resize(index + 1, T::default());
+        }
+        buffer[index] = value;
+    }
+
+    /// Run `f` against the `[begin, end)` slice of the buffer under the lock.
+    pub fn with_slice<R>(&self, begin: usize, end: usize, f: impl FnOnce(&[T]) -> R) -> R {
+        let buffer = self.buffer.lock().unwrap();
+        f(&buffer[begin..end])
+    }
+
+    /// Run `f` against a mutable `[begin, end)` slice of the buffer under the lock.
+    pub fn with_slice_mut<R>(&self, begin: usize, end: usize, f: impl FnOnce(&mut [T]) -> R) -> R {
+        let mut buffer = self.buffer.lock().unwrap();
+        f(&mut buffer[begin..end])
+    }
+
+    /// Get the number of free ranges currently on the stack.
+    ///
+    /// This walks the lock-free stack without synchronizing with concurrent
+    /// pushes/pops, so under contention it is a best-effort snapshot rather
+    /// than an exact count.
+    pub fn available_count(&self) -> usize {
+        let mut count = 0;
+        let (_, mut index) = unpack_head(self.free_head.load(Ordering::Acquire));
+        while index != NIL {
+            count += 1;
+            index = unsafe { (*self.nodes[index].get()).next };
+        }
+        count
+    }
+
+    /// Get the total buffer size.
+    pub fn buffer_size(&self) -> usize {
+        self.buffer.lock().unwrap().len()
+    }
+}
+
+/// Error returned by the const-generic, capacity-bounded pools when no more
+/// inline space is available and the type has no overflow storage to grow
+/// into (e.g. [`FixedDoubleBuffer`], which is purely inline).
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolError {
+    #[error("pool exhausted: no free slot or range available")]
+    Exhausted,
+}
+
+/// Const-generic, capacity-bounded flat object pool.
+///
+/// Mirrors [`FlatObjectPool`] but stores the backing buffer and free-range
+/// slots inline as `[T; N]` / `[(usize, usize); N]` rather than in a growable
+/// `Vec`, so its size is known at compile time. Exhausting the inline buffer
+/// falls back to an overflow `Vec` just like `FlatObjectPool` growing, so
+/// `acquire` never fails.
+///
+/// # Examples
+///
+/// ```
+/// use ry26::FixedFlatObjectPool;
+///
+/// let mut pool: FixedFlatObjectPool<i32, 64> = FixedFlatObjectPool::new();
+/// let (begin, end) = pool.acquire(10);
+/// for i in begin..end {
+///     pool.set(i, i as i32);
+/// }
+/// pool.release(begin, end);
+/// ```
+#[derive(Debug)]
+pub struct FixedFlatObjectPool<T, const N: usize> {
+    buffer: [T; N],
+    len: usize,
+    free_ranges: [(usize, usize); N],
+    free_count: usize,
+    overflow: Vec<T>,
+}
+
+impl<T: Default + Clone, const N: usize> FixedFlatObjectPool<T, N> {
+    /// Create a new fixed-capacity flat object pool with the default (zeroed) elements.
+    pub fn new() -> Self {
+        Self {
+            buffer: core::array::from_fn(|_| T::default()),
+            len: 0,
+            free_ranges: [(0, 0); N],
+            free_count: 0,
+            overflow: Vec::new(),
+        }
+    }
+
+    /// Acquire a slice of the specified size from the pool.
+    ///
+    /// Returns `(begin, end)` indices for the acquired slice. Mirrors
+    /// [`FlatObjectPool::acquire`]: once the inline capacity is exhausted, this
+    /// grows the overflow `Vec` rather than failing.
+    pub fn acquire(&mut self, size: usize) -> (usize, usize) {
+        for i in 0..self.free_count {
+            let (begin, end) = self.free_ranges[i];
+            let range_size = end - begin;
+            if range_size >= size {
+                self.free_ranges[i] = self.free_ranges[self.free_count - 1];
+                self.free_count -= 1;
+
+                if range_size > size {
+                    let new_begin = begin + size;
+                    if self.free_count < N {
+                        self.free_ranges[self.free_count] = (new_begin, end);
+                        self.free_count += 1;
+                    }
+                }
+
+                return (begin, begin + size);
+            }
+        }
+
+        let inline_begin = self.len;
+        let end = inline_begin + size;
+        if end <= N {
+            self.len = end;
+            return (inline_begin, end);
+        }
+
+        // The request doesn't fit in the remaining inline capacity at all; grow the
+        // overflow Vec instead and track whatever's left of the inline tail (if any)
+        // as a free range so it isn't leaked.
+        if inline_begin < N && self.free_count < N {
+            self.free_ranges[self.free_count] = (inline_begin, N);
+            self.free_count += 1;
+        }
+        self.len = N;
+
+        let overflow_begin = N + self.overflow.len();
+        let overflow_end = overflow_begin + size;
+        self.overflow.resize(self.overflow.len() + size, T::default());
+        (overflow_begin, overflow_end)
+    }
+
+    /// Release a slice back to the pool for reuse.
+    ///
+    /// Silently drops the range if the inline free-range slab is already full.
+    /// Released ranges are not coalesced.
+    pub fn release(&mut self, begin: usize, end: usize) {
+        if begin >= end {
+            return;
+        }
+        for i in begin..end {
+            self.set(i, T::default());
+        }
+        if self.free_count < N {
+            self.free_ranges[self.free_count] = (begin, end);
+            self.free_count += 1;
+        }
+    }
+
+    /// Get a reference to an element in the buffer.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < N {
+            self.buffer.get(index)
+        } else {
+            self.overflow.get(index - N)
+        }
+    }
+
+    /// Set the value at the specified index.
+    pub fn set(&mut self, index: usize, value: T) {
+        if index < N {
+            self.buffer[index] = value;
+        } else {
+            let overflow_index = index - N;
+            if overflow_index >= self.overflow.len() {
+                self.overflow.resize(overflow_index + 1, T::default());
+            }
+            self.overflow[overflow_index] = value;
+        }
+    }
+
+    /// Get the number of available free ranges in the pool.
+    pub fn available_count(&self) -> usize {
+        self.free_count
+    }
+
+    /// Get the inline capacity of the pool (`N`).
+    pub fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<T: Default + Clone, const N: usize> Default for FixedFlatObjectPool<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Const-generic, capacity-bounded double buffer.
+///
+/// Mirrors [`DoubleBuffer`] but stores `front`/`back` as inline `[T; CAP]`
+/// arrays with length counters instead of pooled `Vec`s, so `swap` never
+/// allocates and the whole structure works without an allocator. Pushing
+/// past `CAP` returns `Err(PoolError::Exhausted)` instead of growing.
+///
+/// # Examples
+///
+/// ```
+/// use ry26::FixedDoubleBuffer;
+///
+/// let mut buffer: FixedDoubleBuffer<i32, 4> = FixedDoubleBuffer::new();
+/// buffer.push_back(1).unwrap();
+/// buffer.push_back(2).unwrap();
+/// buffer.swap();
+/// assert_eq!(buffer.front(), &[1, 2]);
+/// ```
+#[derive(Debug)]
+pub struct FixedDoubleBuffer<T, const CAP: usize> {
+    front: [T; CAP],
+    front_len: usize,
+    back: [T; CAP],
+    back_len: usize,
+}
+
+impl<T: Default, const CAP: usize> FixedDoubleBuffer<T, CAP> {
+    /// Create a new fixed-capacity double buffer, both sides empty.
+    pub fn new() -> Self {
+        Self {
+            front: core::array::from_fn(|_| T::default()),
+            front_len: 0,
+            back: core::array::from_fn(|_| T::default()),
+            back_len: 0,
+        }
+    }
+
+    /// Get a reference to the front buffer (read buffer).
+    pub fn front(&self) -> &[T] {
+        &self.front[..self.front_len]
+    }
+
+    /// Get a reference to the back buffer (write buffer).
+    pub fn back(&self) -> &[T] {
+        &self.back[..self.back_len]
+    }
+
+    /// Push a value onto the back buffer.
+    ///
+    /// Returns `Err(PoolError::Exhausted)` if the back buffer is already at `CAP`.
+    pub fn push_back(&mut self, value: T) -> Result<(), PoolError> {
+        if self.back_len >= CAP {
+            return Err(PoolError::Exhausted);
+        }
+        self.back[self.back_len] = value;
+        self.back_len += 1;
+        Ok(())
+    }
+
+    /// Swap the front and back buffers.
+    ///
+    /// After swapping, the back buffer becomes the front buffer (for reading)
+    /// and the old front buffer becomes the new, empty back buffer. Unlike
+    /// [`DoubleBuffer::swap`], this never touches an allocator.
+    pub fn swap(&mut self) {
+        std::mem::swap(&mut self.front, &mut self.back);
+        std::mem::swap(&mut self.front_len, &mut self.back_len);
+        self.back_len = 0;
+    }
+
+    /// Clear both buffers.
+    pub fn clear(&mut self) {
+        self.front_len = 0;
+        self.back_len = 0;
+    }
+
+    /// Get the inline capacity of the buffer (`CAP`).
+    pub fn capacity(&self) -> usize {
+        CAP
+    }
+}
+
+impl<T: Default, const CAP: usize> Default for FixedDoubleBuffer<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error returned by [`StaticMemoryPool`] operations.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaticPoolError {
+    #[error("bucket {0} is full")]
+    StoreFull(usize),
+    #[error("data too large for any configured bucket")]
+    DataTooLarge,
+}
+
+/// Configuration for a [`StaticMemoryPool`]'s size classes.
+///
+/// Each `(num_slots, slot_size)` pair describes one bucket: `num_slots` fixed slots, each able
+/// to hold up to `slot_size` elements. `StaticPoolConfig::new(vec![(4, 4), (2, 8), (1, 16)])`
+/// configures 4 slots of 4 elements, 2 slots of 8, and 1 slot of 16.
+#[derive(Debug, Clone)]
+pub struct StaticPoolConfig {
+    buckets: Vec<(usize, usize)>,
+}
+
+impl StaticPoolConfig {
+    /// Create a new pool configuration from `(num_slots, slot_size)` pairs.
+    pub fn new(buckets: Vec<(usize, usize)>) -> Self {
+        Self { buckets }
+    }
+}
+
+/// An opaque handle to a record stored in a [`StaticMemoryPool`], encoding which bucket and
+/// slot it occupies. Only ever produced by [`StaticMemoryPool::add`]; meaningless across
+/// different pool instances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StoreAddr {
+    bucket_index: usize,
+    slot_index: usize,
+}
+
+#[derive(Debug)]
+struct Bucket<T> {
+    slot_size: usize,
+    data: Vec<T>,
+    occupied: Vec<bool>,
+}
+
+/// Bucketed, segregated-size memory pool with deterministic, fragmentation-free storage for
+/// variable-sized records.
+///
+/// Unlike [`FlatObjectPool`]'s best-fit free list over one contiguous buffer, `StaticMemoryPool`
+/// is configured up front with a fixed set of size classes (see [`StaticPoolConfig`]); each
+/// class is its own flat `Vec<T>` of `num_slots * slot_size` elements with a parallel occupancy
+/// `Vec<bool>`. `add` picks the smallest bucket whose slot size fits the data, so records never
+/// fragment the way ranges in a single growable buffer can.
+///
+/// # Examples
+///
+/// ```
+/// use ry26::{StaticMemoryPool, StaticPoolConfig};
+///
+/// let mut pool: StaticMemoryPool<i32> =
+///     StaticMemoryPool::new(StaticPoolConfig::new(vec![(4, 4), (2, 8), (1, 16)]));
+///
+/// let addr = pool.add(&[1, 2, 3]).unwrap();
+/// let mut out = [0; 3];
+/// pool.read(&addr, &mut out).unwrap();
+/// assert_eq!(out, [1, 2, 3]);
+///
+/// pool.free(addr);
+/// ```
+#[derive(Debug)]
+pub struct StaticMemoryPool<T> {
+    buckets: Vec<Bucket<T>>,
+}
+
+impl<T: Default + Clone> StaticMemoryPool<T> {
+    /// Create a new static memory pool from the given size-class configuration.
+    pub fn new(config: StaticPoolConfig) -> Self {
+        let mut buckets: Vec<Bucket<T>> = config
+            .buckets
+            .into_iter()
+            .map(|(num_slots, slot_size)| Bucket {
+                slot_size,
+                data: vec![T::default(); num_slots * slot_size],
+                occupied: vec![false; num_slots],
+            })
+            .collect();
+        // Smallest-fits-first: `add` scans buckets in order, so sorting by slot size
+        // up front is what makes "pick the smallest bucket that fits" a simple left-to-right scan.
+        buckets.sort_by_key(|bucket| bucket.slot_size);
+        Self { buckets }
+    }
+
+    /// Copy `data` into the smallest bucket whose slot size fits it and return a handle to it.
+    ///
+    /// Errors with `StoreFull(bucket_index)` if every bucket large enough for `data` is already
+    /// full, or `DataTooLarge` if no configured bucket is large enough at all.
+    pub fn add(&mut self, data: &[T]) -> Result<StoreAddr, StaticPoolError> {
+        let len = data.len();
+        let mut first_fitting_bucket: Option<usize> = None;
+
+        for (bucket_index, bucket) in self.buckets.iter_mut().enumerate() {
+            if bucket.slot_size < len {
+                continue;
+            }
+            if first_fitting_bucket.is_none() {
+                first_fitting_bucket = Some(bucket_index);
+            }
+            if let Some(slot_index) = bucket.occupied.iter().position(|occupied| !occupied) {
+                bucket.occupied[slot_index] = true;
+                let start = slot_index * bucket.slot_size;
+                bucket.data[start..start + len].clone_from_slice(data);
+                return Ok(StoreAddr {
+                    bucket_index,
+                    slot_index,
+                });
+            }
+        }
+
+        match first_fitting_bucket {
+            Some(bucket_index) => Err(StaticPoolError::StoreFull(bucket_index)),
+            None => Err(StaticPoolError::DataTooLarge),
+        }
+    }
+
+    /// Copy the record at `addr` into `out`, up to `out.len()` or the bucket's slot size,
+    /// whichever is smaller.
+    pub fn read(&self, addr: &StoreAddr, out: &mut [T]) -> Result<(), StaticPoolError> {
+        let bucket = &self.buckets[addr.bucket_index];
+        let start = addr.slot_index * bucket.slot_size;
+        let len = out.len().min(bucket.slot_size);
+        out[..len].clone_from_slice(&bucket.data[start..start + len]);
+        Ok(())
+    }
+
+    /// Run `f` against the record's slot in place.
+    pub fn modify(&mut self, addr: &StoreAddr, f: impl FnOnce(&mut [T])) {
+        let bucket = &mut self.buckets[addr.bucket_index];
+        let start = addr.slot_index * bucket.slot_size;
+        let end = start + bucket.slot_size;
+        f(&mut bucket.data[start..end]);
+    }
+
+    /// Release the slot at `addr` back to its bucket for reuse, clearing its contents.
+    pub fn free(&mut self, addr: StoreAddr) {
+        let bucket = &mut self.buckets[addr.bucket_index];
+        let start = addr.slot_index * bucket.slot_size;
+        let end = start + bucket.slot_size;
+        for slot in &mut bucket.data[start..end] {
+            *slot = T::default();
+        }
+        bucket.occupied[addr.slot_index] = false;
+    }
+
+    /// Get the number of configured buckets (size classes).
+    pub fn bucket_count(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Get the slot size of the bucket at `bucket_index` (buckets are sorted ascending by size).
+    pub fn bucket_slot_size(&self, bucket_index: usize) -> usize {
+        self.buckets[bucket_index].slot_size
+    }
+
+    /// Get the number of free slots remaining in the bucket at `bucket_index`.
+    pub fn bucket_available(&self, bucket_index: usize) -> usize {
+        self.buckets[bucket_index]
+            .occupied
+            .iter()
+            .filter(|occupied| !**occupied)
+            .count()
+    }
+}
+
+impl<T: Default + Clone> PoolProvider<T> for StaticMemoryPool<T> {
+    type Handle = StoreAddr;
+    type Error = StaticPoolError;
+
+    fn acquire(&mut self, data: &[T]) -> Result<Self::Handle, Self::Error> {
+        self.add(data)
+    }
+
+    fn read(&self, handle: &Self::Handle, out: &mut [T]) -> Result<(), Self::Error> {
+        StaticMemoryPool::read(self, handle, out)
+    }
+
+    fn modify(&mut self, handle: &Self::Handle, f: impl FnOnce(&mut [T])) {
+        StaticMemoryPool::modify(self, handle, f)
+    }
+
+    fn release(&mut self, handle: Self::Handle) {
+        self.free(handle)
+    }
+
+    fn available_count(&self) -> usize {
+        (0..self.bucket_count())
+            .map(|i| self.bucket_available(i) * self.bucket_slot_size(i))
+            .sum()
     }
 }
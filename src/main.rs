@@ -1,5 +1,10 @@
 use clap::{Parser, Subcommand};
-use ry26::{DataPoint, add, from_json, generate_random_data_point, to_json};
+use ry26::{
+    add, from_json, from_ndjson, generate_random_data_point, to_json, to_ndjson, DataPoint,
+    DataPointSequence, LibraryError,
+};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
 use std::process;
 
 /// A simple CLI for the ry26 library
@@ -39,6 +44,28 @@ enum Commands {
         /// JSON string to parse
         json: String,
     },
+    /// Generate random data points and stream them to stdout as NDJSON
+    Export {
+        /// Number of random data points to generate
+        #[arg(long, default_value_t = 1)]
+        count: usize,
+    },
+    /// Import NDJSON data points from a file (or stdin, if omitted) into a sequence
+    Import {
+        /// Path to an NDJSON file; reads stdin if omitted
+        #[arg(long)]
+        file: Option<String>,
+    },
+}
+
+/// Parse every NDJSON line from `reader` into a [`DataPointSequence`].
+fn import_ndjson<R: BufRead>(reader: R) -> Result<DataPointSequence, LibraryError> {
+    let mut sequence = DataPointSequence::new(16, 4);
+    for point in from_ndjson(reader) {
+        sequence.add_point(point?);
+    }
+    sequence.update();
+    Ok(sequence)
 }
 
 fn main() {
@@ -88,5 +115,42 @@ fn main() {
                 process::exit(1);
             }
         },
+        Commands::Export { count } => {
+            let mut sequence = DataPointSequence::new(count.max(1), 4);
+            for _ in 0..count {
+                sequence.add_point(generate_random_data_point());
+            }
+            sequence.update();
+
+            let stdout = io::stdout();
+            let mut handle = stdout.lock();
+            if let Err(e) = to_ndjson(sequence.current(), &mut handle) {
+                eprintln!("Error: {}", e);
+                process::exit(1);
+            }
+        }
+        Commands::Import { file } => {
+            let result = match file {
+                Some(path) => File::open(&path)
+                    .map_err(LibraryError::IoError)
+                    .and_then(|f| import_ndjson(BufReader::new(f))),
+                None => import_ndjson(io::stdin().lock()),
+            };
+            match result {
+                Ok(sequence) => {
+                    println!("Imported {} data point(s)", sequence.len());
+                    if let Some(oldest) = sequence.oldest() {
+                        println!("Oldest: id={} timestamp={}", oldest.id, oldest.timestamp);
+                    }
+                    if let Some(newest) = sequence.newest() {
+                        println!("Newest: id={} timestamp={}", newest.id, newest.timestamp);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,75 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[test]
+fn test_cli_export_default_count() {
+    let mut cmd = Command::cargo_bin("ry26").unwrap();
+    cmd.arg("export");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let text = String::from_utf8(output).unwrap();
+    assert_eq!(text.lines().count(), 1);
+}
+
+#[test]
+fn test_cli_export_count() {
+    let mut cmd = Command::cargo_bin("ry26").unwrap();
+    cmd.arg("export").arg("--count").arg("5");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let text = String::from_utf8(output).unwrap();
+    assert_eq!(text.lines().count(), 5);
+    for line in text.lines() {
+        assert!(line.contains("\"id\":"));
+    }
+}
+
+#[test]
+fn test_cli_import_from_stdin() {
+    let mut cmd = Command::cargo_bin("ry26").unwrap();
+    cmd.arg("import").write_stdin(
+        "{\"id\":1,\"value\":1.0,\"timestamp\":\"2025-10-27T12:00:00Z\"}\n\
+         {\"id\":2,\"value\":2.0,\"timestamp\":\"2025-10-27T12:01:00Z\"}\n",
+    );
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Imported 2 data point(s)"));
+}
+
+#[test]
+fn test_cli_import_reports_oldest_and_newest() {
+    let mut cmd = Command::cargo_bin("ry26").unwrap();
+    cmd.arg("import").write_stdin(
+        "{\"id\":1,\"value\":1.0,\"timestamp\":\"2025-10-27T12:00:00Z\"}\n\
+         {\"id\":2,\"value\":2.0,\"timestamp\":\"2025-10-27T12:01:00Z\"}\n",
+    );
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Oldest: id=1"))
+        .stdout(predicate::str::contains("Newest: id=2"));
+}
+
+#[test]
+fn test_cli_import_malformed_line() {
+    let mut cmd = Command::cargo_bin("ry26").unwrap();
+    cmd.arg("import").write_stdin("not json\n");
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Error:"));
+}
+
+#[test]
+fn test_cli_export_help() {
+    let mut cmd = Command::cargo_bin("ry26").unwrap();
+    cmd.arg("export").arg("--help");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Generate random data points"));
+}
+
+#[test]
+fn test_cli_import_help() {
+    let mut cmd = Command::cargo_bin("ry26").unwrap();
+    cmd.arg("import").arg("--help");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("--file"));
+}
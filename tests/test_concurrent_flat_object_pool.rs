@@ -0,0 +1,139 @@
+use ry26::ConcurrentFlatObjectPool;
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn test_concurrent_flat_pool_creation() {
+    let pool: ConcurrentFlatObjectPool<i32> = ConcurrentFlatObjectPool::new(100, 10);
+    assert_eq!(pool.buffer_size(), 100);
+    assert_eq!(pool.available_count(), 0);
+}
+
+#[test]
+fn test_concurrent_flat_pool_acquire() {
+    let pool: ConcurrentFlatObjectPool<i32> = ConcurrentFlatObjectPool::new(100, 10);
+
+    let (begin, end) = pool.acquire(10);
+    assert_eq!(end - begin, 10);
+    assert!(end <= pool.buffer_size());
+}
+
+#[test]
+fn test_concurrent_flat_pool_acquire_and_release() {
+    let pool: ConcurrentFlatObjectPool<i32> = ConcurrentFlatObjectPool::new(100, 10);
+
+    let (begin, end) = pool.acquire(10);
+    assert_eq!(pool.available_count(), 0);
+
+    pool.release(begin, end);
+    assert_eq!(pool.available_count(), 1);
+}
+
+#[test]
+fn test_concurrent_flat_pool_reuse() {
+    let pool: ConcurrentFlatObjectPool<i32> = ConcurrentFlatObjectPool::new(100, 10);
+
+    let (begin1, end1) = pool.acquire(10);
+    pool.release(begin1, end1);
+
+    let (begin2, end2) = pool.acquire(10);
+    assert_eq!(begin1, begin2);
+    assert_eq!(end1, end2);
+}
+
+#[test]
+fn test_concurrent_flat_pool_set_and_get() {
+    let pool: ConcurrentFlatObjectPool<i32> = ConcurrentFlatObjectPool::new(100, 10);
+
+    let (begin, end) = pool.acquire(10);
+    for i in begin..end {
+        pool.set(i, (i * 2) as i32);
+    }
+    for i in begin..end {
+        assert_eq!(pool.get(i), Some((i * 2) as i32));
+    }
+}
+
+#[test]
+fn test_concurrent_flat_pool_release_clears_data() {
+    let pool: ConcurrentFlatObjectPool<i32> = ConcurrentFlatObjectPool::new(100, 10);
+
+    let (begin, end) = pool.acquire(10);
+    for i in begin..end {
+        pool.set(i, 42);
+    }
+    pool.release(begin, end);
+    for i in begin..end {
+        assert_eq!(pool.get(i), Some(0));
+    }
+}
+
+#[test]
+fn test_concurrent_flat_pool_buffer_extension() {
+    let pool: ConcurrentFlatObjectPool<i32> = ConcurrentFlatObjectPool::new(10, 5);
+
+    let initial_size = pool.buffer_size();
+    let (_, end) = pool.acquire(20);
+
+    assert!(pool.buffer_size() > initial_size);
+    assert!(end <= pool.buffer_size());
+}
+
+#[test]
+fn test_concurrent_flat_pool_invalid_range() {
+    let pool: ConcurrentFlatObjectPool<i32> = ConcurrentFlatObjectPool::new(100, 10);
+
+    pool.release(50, 50);
+    pool.release(60, 50);
+
+    assert_eq!(pool.available_count(), 0);
+}
+
+#[test]
+fn test_concurrent_flat_pool_two_threads_acquire_disjoint_ranges() {
+    let pool: Arc<ConcurrentFlatObjectPool<i32>> = Arc::new(ConcurrentFlatObjectPool::new(0, 32));
+
+    let mut handles = Vec::new();
+    for t in 0..2 {
+        let pool = Arc::clone(&pool);
+        handles.push(thread::spawn(move || {
+            let mut ranges = Vec::new();
+            for i in 0..8 {
+                let (begin, end) = pool.acquire(4);
+                for idx in begin..end {
+                    pool.set(idx, t * 100 + i);
+                }
+                ranges.push((begin, end));
+            }
+            ranges
+        }));
+    }
+
+    let mut all_ranges = Vec::new();
+    for handle in handles {
+        all_ranges.extend(handle.join().unwrap());
+    }
+
+    // Every thread's acquired ranges must be disjoint from every other's.
+    all_ranges.sort_unstable();
+    for pair in all_ranges.windows(2) {
+        assert!(pair[0].1 <= pair[1].0);
+    }
+
+    for (begin, end) in all_ranges {
+        pool.release(begin, end);
+    }
+    assert!(pool.available_count() > 0);
+}
+
+#[test]
+fn test_concurrent_flat_pool_capacity_limit() {
+    let pool: ConcurrentFlatObjectPool<i32> = ConcurrentFlatObjectPool::new(100, 3);
+
+    for _ in 0..5 {
+        let (b, e) = pool.acquire(5);
+        pool.release(b, e);
+    }
+
+    assert!(pool.available_count() <= 3);
+}
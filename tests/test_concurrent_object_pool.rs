@@ -0,0 +1,65 @@
+use ry26::ConcurrentObjectPool;
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn test_concurrent_object_pool_creation() {
+    let pool: ConcurrentObjectPool<i32> = ConcurrentObjectPool::new(10);
+    assert_eq!(pool.available_count(), 0);
+}
+
+#[test]
+fn test_concurrent_object_pool_acquire_when_empty() {
+    let pool: ConcurrentObjectPool<i32> = ConcurrentObjectPool::new(5);
+    let vec = pool.acquire();
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn test_concurrent_object_pool_release_and_reuse() {
+    let pool: ConcurrentObjectPool<i32> = ConcurrentObjectPool::new(5);
+
+    let mut vec = pool.acquire();
+    vec.push(1);
+    vec.push(2);
+    pool.release(vec);
+    assert_eq!(pool.available_count(), 1);
+
+    let reused = pool.acquire();
+    assert!(reused.is_empty());
+    assert_eq!(pool.available_count(), 0);
+}
+
+#[test]
+fn test_concurrent_object_pool_capacity_limit() {
+    let pool: ConcurrentObjectPool<i32> = ConcurrentObjectPool::new(3);
+
+    for _ in 0..5 {
+        pool.release(Vec::new());
+    }
+
+    assert!(pool.available_count() <= 3);
+}
+
+#[test]
+fn test_concurrent_object_pool_shared_across_threads() {
+    let pool: Arc<ConcurrentObjectPool<i32>> = Arc::new(ConcurrentObjectPool::new(32));
+
+    let mut handles = Vec::new();
+    for t in 0..4 {
+        let pool = Arc::clone(&pool);
+        handles.push(thread::spawn(move || {
+            for i in 0..20 {
+                let mut vec = pool.acquire();
+                vec.push(t * 100 + i);
+                pool.release(vec);
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert!(pool.available_count() <= 32);
+}
@@ -0,0 +1,107 @@
+use ry26::{DataPoint, DataPointSequence};
+
+fn point(id: u64) -> DataPoint {
+    DataPoint {
+        id,
+        value: id as f64,
+        timestamp: format!("2025-10-27T12:{:02}:00Z", id),
+    }
+}
+
+#[test]
+fn test_snapshot_matches_current_at_time_of_capture() {
+    let mut sequence = DataPointSequence::new(10, 4);
+    sequence.add_point(point(1));
+    sequence.update();
+
+    let snapshot = sequence.snapshot();
+    assert_eq!(snapshot.step(), 1);
+    assert_eq!(snapshot.len(), 1);
+    assert_eq!(snapshot.as_slice()[0].id, 1);
+}
+
+#[test]
+fn test_snapshot_unaffected_by_later_update() {
+    let mut sequence = DataPointSequence::new(10, 4);
+    sequence.add_point(point(1));
+    sequence.update();
+
+    let snapshot = sequence.snapshot();
+
+    sequence.add_point(point(2));
+    sequence.update();
+
+    assert_eq!(snapshot.len(), 1);
+    assert_eq!(sequence.len(), 2);
+}
+
+#[test]
+fn test_snapshot_unaffected_by_later_clear() {
+    let mut sequence = DataPointSequence::new(10, 4);
+    sequence.add_point(point(1));
+    sequence.add_point(point(2));
+    sequence.update();
+
+    let snapshot = sequence.snapshot();
+    sequence.clear();
+
+    assert_eq!(snapshot.len(), 2);
+    assert!(sequence.is_empty());
+}
+
+#[test]
+fn test_snapshot_iter_and_into_iter() {
+    let mut sequence = DataPointSequence::new(10, 4);
+    sequence.add_points(vec![point(1), point(2), point(3)]);
+    sequence.update();
+
+    let snapshot = sequence.snapshot();
+    let via_iter: Vec<u64> = snapshot.iter().map(|p| p.id).collect();
+    let via_into_iter: Vec<u64> = (&snapshot).into_iter().map(|p| p.id).collect();
+
+    assert_eq!(via_iter, vec![1, 2, 3]);
+    assert_eq!(via_into_iter, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_sequence_iter_insertion_order() {
+    let mut sequence = DataPointSequence::new(10, 4);
+    sequence.add_points(vec![point(1), point(2)]);
+    sequence.update();
+    sequence.add_point(point(3));
+    sequence.update();
+
+    let ids: Vec<u64> = sequence.iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+
+    let ids_via_into_iter: Vec<u64> = (&sequence).into_iter().map(|p| p.id).collect();
+    assert_eq!(ids_via_into_iter, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_sequence_range_filters_by_step() {
+    let mut sequence = DataPointSequence::new(10, 4);
+
+    sequence.add_point(point(1));
+    sequence.update(); // step 1
+
+    sequence.add_point(point(2));
+    sequence.update(); // step 2
+
+    sequence.add_point(point(3));
+    sequence.update(); // step 3
+
+    let ids: Vec<u64> = sequence.range(2..4).map(|p| p.id).collect();
+    assert_eq!(ids, vec![2, 3]);
+
+    let none: Vec<u64> = sequence.range(10..20).map(|p| p.id).collect();
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_sequence_snapshot_empty_sequence() {
+    let sequence = DataPointSequence::new(10, 4);
+    let snapshot = sequence.snapshot();
+    assert!(snapshot.is_empty());
+    assert_eq!(snapshot.step(), 0);
+}
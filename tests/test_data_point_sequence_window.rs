@@ -0,0 +1,105 @@
+use ry26::{DataPoint, DataPointSequence};
+
+fn point(id: u64) -> DataPoint {
+    DataPoint {
+        id,
+        value: id as f64 * 10.0,
+        timestamp: format!("2025-10-27T12:{:02}:00Z", id),
+    }
+}
+
+#[test]
+fn test_window_sequence_creation() {
+    let sequence = DataPointSequence::with_window(10, 4, 3);
+    assert_eq!(sequence.len(), 0);
+    assert!(sequence.is_empty());
+}
+
+#[test]
+fn test_window_sequence_below_capacity() {
+    let mut sequence = DataPointSequence::with_window(10, 4, 3);
+    sequence.add_point(point(1));
+    sequence.update();
+    sequence.add_point(point(2));
+    sequence.update();
+
+    assert_eq!(sequence.len(), 2);
+    assert_eq!(sequence.current()[0].id, 1);
+    assert_eq!(sequence.current()[1].id, 2);
+}
+
+#[test]
+fn test_window_sequence_evicts_oldest() {
+    let mut sequence = DataPointSequence::with_window(10, 4, 3);
+    for i in 1..=5 {
+        sequence.add_point(point(i));
+        sequence.update();
+    }
+
+    // Window never exceeds window_len, even though 5 points were committed.
+    assert_eq!(sequence.len(), 3);
+    let ids: Vec<u64> = sequence.current().iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![3, 4, 5]);
+}
+
+#[test]
+fn test_window_sequence_chronological_order_after_wrap() {
+    let mut sequence = DataPointSequence::with_window(10, 4, 3);
+    for i in 1..=7 {
+        sequence.add_point(point(i));
+        sequence.update();
+    }
+
+    let ids: Vec<u64> = sequence.current().iter().map(|p| p.id).collect();
+    assert_eq!(ids, vec![5, 6, 7]);
+}
+
+#[test]
+fn test_window_sequence_oldest_and_newest() {
+    let mut sequence = DataPointSequence::with_window(10, 4, 3);
+    for i in 1..=4 {
+        sequence.add_point(point(i));
+        sequence.update();
+    }
+
+    assert_eq!(sequence.oldest().unwrap().id, 2);
+    assert_eq!(sequence.newest().unwrap().id, 4);
+}
+
+#[test]
+fn test_window_sequence_buffer_stays_bounded() {
+    let mut sequence = DataPointSequence::with_window(2, 4, 3);
+    for i in 1..=20 {
+        sequence.add_point(point(i));
+        sequence.update();
+    }
+
+    assert_eq!(sequence.len(), 3);
+    // The committed buffer never holds more points than the window length.
+    assert!(sequence.buffer_size() <= 3);
+}
+
+#[test]
+fn test_window_sequence_clear() {
+    let mut sequence = DataPointSequence::with_window(10, 4, 3);
+    for i in 1..=5 {
+        sequence.add_point(point(i));
+        sequence.update();
+    }
+    sequence.clear();
+
+    assert_eq!(sequence.len(), 0);
+    assert!(sequence.is_empty());
+    assert_eq!(sequence.step(), 0);
+}
+
+#[test]
+fn test_unbounded_sequence_oldest_newest_still_work() {
+    let mut sequence = DataPointSequence::new(10, 4);
+    sequence.add_point(point(1));
+    sequence.add_point(point(2));
+    sequence.update();
+
+    assert_eq!(sequence.oldest().unwrap().id, 1);
+    assert_eq!(sequence.newest().unwrap().id, 2);
+}
@@ -0,0 +1,96 @@
+use ry26::DoubleBuffer;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn test_spsc_initial_consumer_sees_original_front() {
+    let mut buffer: DoubleBuffer<i32> = DoubleBuffer::new(4);
+    buffer.back_mut().push(1);
+    buffer.swap();
+
+    let (_producer, consumer) = buffer.into_spsc();
+    assert_eq!(consumer.latest(), &[1]);
+}
+
+#[test]
+fn test_spsc_consume_without_publish_returns_false() {
+    let buffer: DoubleBuffer<i32> = DoubleBuffer::new(4);
+    let (_producer, mut consumer) = buffer.into_spsc();
+    assert!(!consumer.consume());
+}
+
+#[test]
+fn test_spsc_publish_then_consume_delivers_data() {
+    let buffer: DoubleBuffer<i32> = DoubleBuffer::new(4);
+    let (mut producer, mut consumer) = buffer.into_spsc();
+
+    producer.back_mut().push(10);
+    producer.back_mut().push(20);
+    producer.publish();
+
+    assert!(consumer.consume());
+    assert_eq!(consumer.latest(), &[10, 20]);
+}
+
+#[test]
+fn test_spsc_repeated_publish_consume_cycles() {
+    let buffer: DoubleBuffer<i32> = DoubleBuffer::new(4);
+    let (mut producer, mut consumer) = buffer.into_spsc();
+
+    for round in 0..5 {
+        producer.back_mut().clear();
+        producer.back_mut().push(round);
+        producer.publish();
+
+        assert!(consumer.consume());
+        assert_eq!(consumer.latest(), &[round]);
+    }
+}
+
+#[test]
+fn test_spsc_unconsumed_publish_is_overwritten_by_next_publish() {
+    let buffer: DoubleBuffer<i32> = DoubleBuffer::new(4);
+    let (mut producer, mut consumer) = buffer.into_spsc();
+
+    producer.back_mut().push(1);
+    producer.publish();
+
+    producer.back_mut().clear();
+    producer.back_mut().push(2);
+    producer.publish();
+
+    assert!(consumer.consume());
+    assert_eq!(consumer.latest(), &[2]);
+}
+
+#[test]
+fn test_spsc_producer_and_consumer_on_separate_threads() {
+    let buffer: DoubleBuffer<i32> = DoubleBuffer::new(4);
+    let (mut producer, mut consumer) = buffer.into_spsc();
+
+    let writer = thread::spawn(move || {
+        for i in 0..20 {
+            producer.back_mut().clear();
+            producer.back_mut().push(i);
+            producer.publish();
+            thread::sleep(Duration::from_micros(50));
+        }
+    });
+
+    let reader = thread::spawn(move || {
+        let mut last_seen = -1;
+        for _ in 0..500 {
+            if consumer.consume() {
+                let value = consumer.latest()[0];
+                assert!(value >= last_seen);
+                last_seen = value;
+            }
+            thread::sleep(Duration::from_micros(10));
+        }
+        last_seen
+    });
+
+    writer.join().unwrap();
+    let last_seen = reader.join().unwrap();
+    assert!(last_seen >= 0);
+}
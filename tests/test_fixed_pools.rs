@@ -0,0 +1,101 @@
+use ry26::{FixedDoubleBuffer, FixedFlatObjectPool, PoolError};
+
+#[test]
+fn test_fixed_flat_pool_creation() {
+    let pool: FixedFlatObjectPool<i32, 16> = FixedFlatObjectPool::new();
+    assert_eq!(pool.capacity(), 16);
+    assert_eq!(pool.available_count(), 0);
+}
+
+#[test]
+fn test_fixed_flat_pool_acquire_and_set() {
+    let mut pool: FixedFlatObjectPool<i32, 16> = FixedFlatObjectPool::new();
+    let (begin, end) = pool.acquire(4);
+    for i in begin..end {
+        pool.set(i, i as i32);
+    }
+    for i in begin..end {
+        assert_eq!(pool.get(i), Some(&(i as i32)));
+    }
+}
+
+#[test]
+fn test_fixed_flat_pool_acquire_and_release_reuse() {
+    let mut pool: FixedFlatObjectPool<i32, 16> = FixedFlatObjectPool::new();
+    let (begin1, end1) = pool.acquire(4);
+    pool.release(begin1, end1);
+    let (begin2, end2) = pool.acquire(4);
+    assert_eq!(begin1, begin2);
+    assert_eq!(end1, end2);
+}
+
+#[test]
+fn test_fixed_flat_pool_acquire_grows_via_overflow() {
+    let mut pool: FixedFlatObjectPool<i32, 4> = FixedFlatObjectPool::new();
+    let (first_begin, first_end) = pool.acquire(4);
+    assert_eq!((first_begin, first_end), (0, 4));
+
+    // Exhausting the inline capacity grows an overflow Vec instead of failing.
+    let (second_begin, second_end) = pool.acquire(3);
+    assert_eq!((second_begin, second_end), (4, 7));
+    for i in second_begin..second_end {
+        pool.set(i, i as i32);
+    }
+    for i in second_begin..second_end {
+        assert_eq!(pool.get(i), Some(&(i as i32)));
+    }
+}
+
+#[test]
+fn test_fixed_flat_pool_partial_inline_tail_not_leaked() {
+    // N=10, first acquire takes 8, leaving 2 inline slots; a request for 4 doesn't fit
+    // the remaining 2, so it spills to overflow. The 2 leftover inline slots must be
+    // tracked as free rather than leaked.
+    let mut pool: FixedFlatObjectPool<i32, 10> = FixedFlatObjectPool::new();
+    let (first_begin, first_end) = pool.acquire(8);
+    assert_eq!((first_begin, first_end), (0, 8));
+
+    let (second_begin, second_end) = pool.acquire(4);
+    assert_eq!((second_begin, second_end), (10, 14));
+    assert_eq!(pool.available_count(), 1);
+
+    let (third_begin, third_end) = pool.acquire(2);
+    assert_eq!((third_begin, third_end), (8, 10));
+    assert_eq!(pool.available_count(), 0);
+}
+
+#[test]
+fn test_fixed_double_buffer_creation() {
+    let buffer: FixedDoubleBuffer<i32, 4> = FixedDoubleBuffer::new();
+    assert_eq!(buffer.capacity(), 4);
+    assert_eq!(buffer.front().len(), 0);
+}
+
+#[test]
+fn test_fixed_double_buffer_push_and_swap() {
+    let mut buffer: FixedDoubleBuffer<i32, 4> = FixedDoubleBuffer::new();
+    buffer.push_back(1).unwrap();
+    buffer.push_back(2).unwrap();
+    buffer.swap();
+
+    assert_eq!(buffer.front(), &[1, 2]);
+    assert_eq!(buffer.back().len(), 0);
+}
+
+#[test]
+fn test_fixed_double_buffer_exhausted() {
+    let mut buffer: FixedDoubleBuffer<i32, 2> = FixedDoubleBuffer::new();
+    buffer.push_back(1).unwrap();
+    buffer.push_back(2).unwrap();
+    assert_eq!(buffer.push_back(3), Err(PoolError::Exhausted));
+}
+
+#[test]
+fn test_fixed_double_buffer_clear() {
+    let mut buffer: FixedDoubleBuffer<i32, 4> = FixedDoubleBuffer::new();
+    buffer.push_back(1).unwrap();
+    buffer.swap();
+    buffer.clear();
+    assert_eq!(buffer.front().len(), 0);
+    assert_eq!(buffer.back().len(), 0);
+}
@@ -20,10 +20,13 @@ fn test_flat_pool_acquire() {
 #[test]
 fn test_flat_pool_acquire_and_release() {
     let mut pool: FlatObjectPool<i32> = FlatObjectPool::new(100, 10);
-    
+
     let (begin, end) = pool.acquire(10);
+    // Keep a second allocation alive so releasing the first range doesn't reach the
+    // buffer's tail and get reclaimed by truncation instead of tracked as free.
+    let _pad = pool.acquire(1);
     assert_eq!(pool.available_count(), 0);
-    
+
     pool.release(begin, end);
     assert_eq!(pool.available_count(), 1);
 }
@@ -131,17 +134,21 @@ fn test_flat_pool_multiple_ranges() {
 #[test]
 fn test_flat_pool_release_clears_data() {
     let mut pool: FlatObjectPool<i32> = FlatObjectPool::new(100, 10);
-    
+
     let (begin, end) = pool.acquire(10);
-    
+    // Keep a second allocation alive so the released range isn't at the buffer's tail,
+    // where it would be truncated away (and its indices become invalid) instead of kept
+    // around with cleared data.
+    let _pad = pool.acquire(1);
+
     // Set values
     for i in begin..end {
         pool.set(i, 42);
     }
-    
+
     // Release
     pool.release(begin, end);
-    
+
     // Values should be cleared to default
     for i in begin..end {
         assert_eq!(pool.get(i), Some(&0));
@@ -191,16 +198,18 @@ fn test_flat_pool_invalid_range() {
 #[test]
 fn test_flat_pool_partial_range_reuse() {
     let mut pool: FlatObjectPool<i32> = FlatObjectPool::new(100, 10);
-    
-    // Acquire and release a large range
+
+    // Acquire a large range, then a second one so the first isn't at the buffer's tail
+    // (a tail-aligned free range would be reclaimed by truncation instead of kept around).
     let (begin, end) = pool.acquire(20);
+    let _tail_range = pool.acquire(5);
     pool.release(begin, end);
-    
+
     // Acquire a smaller range - should reuse part of it
     let (begin2, end2) = pool.acquire(10);
     assert_eq!(begin, begin2);
     assert_eq!(end2 - begin2, 10);
-    
+
     // Should have leftover in free list
     assert!(pool.available_count() > 0);
 }
@@ -208,9 +217,13 @@ fn test_flat_pool_partial_range_reuse() {
 #[test]
 fn test_flat_pool_with_strings() {
     let mut pool: FlatObjectPool<String> = FlatObjectPool::new(50, 5);
-    
+
     let (begin, end) = pool.acquire(5);
-    
+    // Keep a second allocation alive so the released range isn't at the buffer's tail,
+    // where it would be truncated away (and its indices become invalid) instead of kept
+    // around with cleared data.
+    let _pad = pool.acquire(1);
+
     // Set string values
     for i in begin..end {
         pool.set(i, format!("String {}", i));
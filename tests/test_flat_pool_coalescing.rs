@@ -0,0 +1,110 @@
+use ry26::FlatObjectPool;
+
+#[test]
+fn test_flat_pool_available_bytes_tracks_free_ranges() {
+    let mut pool: FlatObjectPool<i32> = FlatObjectPool::new(100, 10);
+    assert_eq!(pool.available_bytes(), 0);
+
+    let (b1, e1) = pool.acquire(10);
+    let _keep_alive = pool.acquire(5);
+    pool.release(b1, e1);
+    assert_eq!(pool.available_bytes(), 10);
+}
+
+#[test]
+fn test_flat_pool_coalesces_adjacent_ranges() {
+    let mut pool: FlatObjectPool<i32> = FlatObjectPool::new(100, 10);
+
+    let (b1, e1) = pool.acquire(10);
+    let (b2, e2) = pool.acquire(10);
+    let _keep_alive = pool.acquire(5);
+    assert_eq!(e1, b2);
+
+    pool.release(b1, e1);
+    pool.release(b2, e2);
+
+    // The two released ranges are adjacent, so they merge into a single free range.
+    assert_eq!(pool.available_count(), 1);
+    assert_eq!(pool.available_bytes(), 20);
+
+    // A request for the full merged size should reuse it exactly, leaving nothing behind.
+    let (begin, end) = pool.acquire(20);
+    assert_eq!((begin, end), (b1, e2));
+    assert_eq!(pool.available_count(), 0);
+}
+
+#[test]
+fn test_flat_pool_coalesces_regardless_of_release_order() {
+    let mut pool: FlatObjectPool<i32> = FlatObjectPool::new(100, 10);
+
+    let (b1, e1) = pool.acquire(10);
+    let (b2, e2) = pool.acquire(10);
+    let _keep_alive = pool.acquire(5);
+
+    // Release the second range first, then the first: coalescing must still merge them.
+    pool.release(b2, e2);
+    pool.release(b1, e1);
+
+    assert_eq!(pool.available_count(), 1);
+    assert_eq!(pool.available_bytes(), 20);
+}
+
+#[test]
+fn test_flat_pool_best_fit_prefers_smaller_range() {
+    let mut pool: FlatObjectPool<i32> = FlatObjectPool::new(100, 10);
+
+    let (b_small, e_small) = pool.acquire(5);
+    // A range that stays allocated keeps `b_small`/`e_small` and the soon-to-be-released
+    // large range from being adjacent, so releasing both leaves two disjoint free ranges
+    // instead of one coalesced one.
+    let _pad = pool.acquire(3);
+    let (b_large, e_large) = pool.acquire(20);
+    let _keep_alive = pool.acquire(5);
+
+    pool.release(b_large, e_large);
+    pool.release(b_small, e_small);
+
+    // Two disjoint free ranges (5 and 20 elements); a request for 5 should take the
+    // exact-fit small range rather than splitting the larger one.
+    let (begin, end) = pool.acquire(5);
+    assert_eq!((begin, end), (b_small, e_small));
+    assert_eq!(pool.available_count(), 1);
+    assert_eq!(pool.available_bytes(), 20);
+}
+
+#[test]
+fn test_flat_pool_tail_release_reclaims_buffer() {
+    let mut pool: FlatObjectPool<i32> = FlatObjectPool::new(10, 5);
+
+    let (begin, end) = pool.acquire(20);
+    assert_eq!(pool.buffer_size(), 30);
+
+    pool.release(begin, end);
+
+    // The released range reached the end of the buffer, so it is truncated away
+    // instead of being tracked as a free range.
+    assert_eq!(pool.buffer_size(), 10);
+    assert_eq!(pool.available_count(), 0);
+    assert_eq!(pool.available_bytes(), 0);
+}
+
+#[test]
+fn test_flat_pool_tail_reclamation_after_coalescing() {
+    let mut pool: FlatObjectPool<i32> = FlatObjectPool::new(0, 10);
+
+    let (b1, e1) = pool.acquire(10);
+    let (b2, e2) = pool.acquire(10);
+    assert_eq!(pool.buffer_size(), 20);
+
+    // Releasing the non-tail range first leaves a single free range that doesn't yet
+    // reach the buffer's end.
+    pool.release(b1, e1);
+    assert_eq!(pool.buffer_size(), 20);
+    assert_eq!(pool.available_count(), 1);
+
+    // Releasing the tail range coalesces it with the first, and the merged range now
+    // reaches the end of the buffer, so it is reclaimed entirely.
+    pool.release(b2, e2);
+    assert_eq!(pool.buffer_size(), 0);
+    assert_eq!(pool.available_count(), 0);
+}
@@ -0,0 +1,73 @@
+use ry26::{from_ndjson, to_ndjson, DataPoint, LibraryError};
+use std::io::Cursor;
+
+fn point(id: u64) -> DataPoint {
+    DataPoint {
+        id,
+        value: id as f64,
+        timestamp: format!("2025-10-27T12:{:02}:00Z", id),
+    }
+}
+
+#[test]
+fn test_to_ndjson_one_line_per_point() {
+    let points = vec![point(1), point(2), point(3)];
+    let mut out = Vec::new();
+    to_ndjson(&points, &mut out).unwrap();
+
+    let text = String::from_utf8(out).unwrap();
+    assert_eq!(text.lines().count(), 3);
+    assert!(text.lines().next().unwrap().contains("\"id\":1"));
+}
+
+#[test]
+fn test_ndjson_round_trip() {
+    let points = vec![point(1), point(2), point(3)];
+    let mut out = Vec::new();
+    to_ndjson(&points, &mut out).unwrap();
+
+    let parsed: Vec<DataPoint> = from_ndjson(Cursor::new(out))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(parsed, points);
+}
+
+#[test]
+fn test_from_ndjson_skips_blank_lines() {
+    let input = "{\"id\":1,\"value\":1.0,\"timestamp\":\"t\"}\n\n{\"id\":2,\"value\":2.0,\"timestamp\":\"t\"}\n";
+    let parsed: Vec<DataPoint> = from_ndjson(Cursor::new(input))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(parsed.len(), 2);
+}
+
+#[test]
+fn test_from_ndjson_reports_line_number_on_malformed_line() {
+    let input = "{\"id\":1,\"value\":1.0,\"timestamp\":\"t\"}\nnot json\n{\"id\":3,\"value\":3.0,\"timestamp\":\"t\"}\n";
+    let results: Vec<_> = from_ndjson(Cursor::new(input)).collect();
+
+    assert!(results[0].is_ok());
+    match &results[1] {
+        Err(LibraryError::NdjsonParseError { line, .. }) => assert_eq!(*line, 2),
+        other => panic!("expected NdjsonParseError, got {:?}", other),
+    }
+    assert!(results[2].is_ok());
+}
+
+#[test]
+fn test_from_ndjson_lazy_does_not_abort_stream() {
+    let input = "bad line\n{\"id\":5,\"value\":5.0,\"timestamp\":\"t\"}\n";
+    let results: Vec<_> = from_ndjson(Cursor::new(input)).collect();
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_err());
+    assert_eq!(results[1].as_ref().unwrap().id, 5);
+}
+
+#[test]
+fn test_to_ndjson_empty_slice_writes_nothing() {
+    let points: Vec<DataPoint> = Vec::new();
+    let mut out = Vec::new();
+    to_ndjson(&points, &mut out).unwrap();
+    assert!(out.is_empty());
+}
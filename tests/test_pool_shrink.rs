@@ -0,0 +1,71 @@
+use ry26::ObjectPool;
+
+#[test]
+fn test_with_shrink_creation() {
+    let pool: ObjectPool<i32> = ObjectPool::with_shrink(5, 16);
+    assert_eq!(pool.available_count(), 0);
+}
+
+#[test]
+fn test_release_shrinks_oversized_vector() {
+    let mut pool: ObjectPool<i32> = ObjectPool::with_shrink(5, 4);
+
+    let mut vec = pool.acquire();
+    vec.reserve(100);
+    assert!(vec.capacity() >= 100);
+
+    pool.release(vec);
+
+    let reused = pool.acquire();
+    assert!(reused.capacity() <= 4);
+}
+
+#[test]
+fn test_release_leaves_small_vector_untouched() {
+    let mut pool: ObjectPool<i32> = ObjectPool::with_shrink(5, 64);
+
+    let mut vec = pool.acquire();
+    vec.reserve(4);
+    let capacity_before = vec.capacity();
+
+    pool.release(vec);
+
+    let reused = pool.acquire();
+    assert_eq!(reused.capacity(), capacity_before);
+}
+
+#[test]
+fn test_new_does_not_shrink() {
+    let mut pool: ObjectPool<i32> = ObjectPool::new(5);
+
+    let mut vec = pool.acquire();
+    vec.reserve(100);
+    let capacity_before = vec.capacity();
+
+    pool.release(vec);
+
+    let reused = pool.acquire();
+    assert_eq!(reused.capacity(), capacity_before);
+}
+
+#[test]
+fn test_shrink_still_respects_pool_capacity() {
+    let mut pool: ObjectPool<i32> = ObjectPool::with_shrink(1, 16);
+    pool.release(Vec::new());
+    assert_eq!(pool.available_count(), 1);
+
+    pool.release(Vec::new());
+    assert_eq!(pool.available_count(), 1);
+}
+
+#[test]
+fn test_pooled_vec_guard_respects_shrink_threshold() {
+    let mut pool: ObjectPool<i32> = ObjectPool::with_shrink(5, 4);
+    {
+        let mut guard = pool.acquire_guarded();
+        guard.reserve(100);
+    }
+
+    let reused = pool.acquire();
+    assert!(reused.capacity() <= 4);
+}
@@ -0,0 +1,75 @@
+use ry26::ObjectPool;
+
+#[test]
+fn test_pooled_vec_starts_empty() {
+    let mut pool: ObjectPool<i32> = ObjectPool::new(5);
+    let guard = pool.acquire_guarded();
+    assert!(guard.is_empty());
+}
+
+#[test]
+fn test_pooled_vec_derefs_for_reads_and_writes() {
+    let mut pool: ObjectPool<i32> = ObjectPool::new(5);
+    let mut guard = pool.acquire_guarded();
+    guard.push(1);
+    guard.push(2);
+    guard.push(3);
+    assert_eq!(*guard, vec![1, 2, 3]);
+    assert_eq!(guard.len(), 3);
+}
+
+#[test]
+fn test_pooled_vec_returns_to_pool_on_drop() {
+    let mut pool: ObjectPool<i32> = ObjectPool::new(5);
+    {
+        let mut guard = pool.acquire_guarded();
+        guard.push(1);
+    }
+    assert_eq!(pool.available_count(), 1);
+}
+
+#[test]
+fn test_pooled_vec_cleared_on_drop() {
+    let mut pool: ObjectPool<i32> = ObjectPool::new(5);
+    {
+        let mut guard = pool.acquire_guarded();
+        guard.push(1);
+        guard.push(2);
+    }
+
+    let reused = pool.acquire();
+    assert!(reused.is_empty());
+}
+
+#[test]
+fn test_pooled_vec_dropped_instead_of_released_when_pool_full() {
+    // A zero-capacity pool is always at capacity, even while the guard holds its vector.
+    let mut pool: ObjectPool<i32> = ObjectPool::new(0);
+    assert_eq!(pool.available_count(), 0);
+
+    {
+        let mut guard = pool.acquire_guarded();
+        guard.push(1);
+    }
+
+    // The pool was already at capacity, so the returned vector was dropped, not stored.
+    assert_eq!(pool.available_count(), 0);
+}
+
+#[test]
+fn test_pooled_vec_returns_on_early_return() {
+    fn push_and_return_early(pool: &mut ObjectPool<i32>) -> usize {
+        let mut guard = pool.acquire_guarded();
+        guard.push(1);
+        if guard.len() == 1 {
+            return guard.len();
+        }
+        guard.push(2);
+        guard.len()
+    }
+
+    let mut pool: ObjectPool<i32> = ObjectPool::new(5);
+    let result = push_and_return_early(&mut pool);
+    assert_eq!(result, 1);
+    assert_eq!(pool.available_count(), 1);
+}
@@ -0,0 +1,104 @@
+use ry26::{StaticMemoryPool, StaticPoolConfig, StaticPoolError};
+
+fn pool() -> StaticMemoryPool<i32> {
+    StaticMemoryPool::new(StaticPoolConfig::new(vec![(4, 4), (2, 8), (1, 16)]))
+}
+
+#[test]
+fn test_static_pool_creation() {
+    let pool = pool();
+    assert_eq!(pool.bucket_count(), 3);
+    assert_eq!(pool.bucket_slot_size(0), 4);
+    assert_eq!(pool.bucket_slot_size(1), 8);
+    assert_eq!(pool.bucket_slot_size(2), 16);
+}
+
+#[test]
+fn test_static_pool_add_picks_smallest_fitting_bucket() {
+    let mut pool = pool();
+    let addr = pool.add(&[1, 2, 3]).unwrap();
+
+    let mut out = [0; 3];
+    pool.read(&addr, &mut out).unwrap();
+    assert_eq!(out, [1, 2, 3]);
+    assert_eq!(pool.bucket_available(0), 3);
+}
+
+#[test]
+fn test_static_pool_add_picks_bigger_bucket_when_needed() {
+    let mut pool = pool();
+    let addr = pool.add(&[1; 6]).unwrap();
+    assert_eq!(pool.bucket_available(0), 4);
+    assert_eq!(pool.bucket_available(1), 1);
+
+    let mut out = [0; 6];
+    pool.read(&addr, &mut out).unwrap();
+    assert_eq!(out, [1; 6]);
+}
+
+#[test]
+fn test_static_pool_data_too_large() {
+    let mut pool = pool();
+    let result = pool.add(&[0; 17]);
+    assert_eq!(result, Err(StaticPoolError::DataTooLarge));
+}
+
+#[test]
+fn test_static_pool_store_full() {
+    let mut pool = pool();
+    for _ in 0..4 {
+        pool.add(&[1, 2]).unwrap();
+    }
+    // The 4-element bucket is now full; a 2-element record still fits it specifically,
+    // but every bucket large enough for it is exhausted once the bigger ones fill too.
+    for _ in 0..2 {
+        pool.add(&[1; 5]).unwrap();
+    }
+    pool.add(&[1; 9]).unwrap();
+
+    let result = pool.add(&[1, 2]);
+    assert_eq!(result, Err(StaticPoolError::StoreFull(0)));
+}
+
+#[test]
+fn test_static_pool_modify() {
+    let mut pool = pool();
+    let addr = pool.add(&[1, 2, 3]).unwrap();
+
+    pool.modify(&addr, |slot| {
+        for value in slot.iter_mut() {
+            *value *= 10;
+        }
+    });
+
+    let mut out = [0; 4];
+    pool.read(&addr, &mut out).unwrap();
+    assert_eq!(out, [10, 20, 30, 0]);
+}
+
+#[test]
+fn test_static_pool_free_allows_reuse() {
+    let mut pool = pool();
+    let addr = pool.add(&[1, 2, 3]).unwrap();
+    assert_eq!(pool.bucket_available(0), 3);
+
+    pool.free(addr);
+    assert_eq!(pool.bucket_available(0), 4);
+
+    let addr2 = pool.add(&[9, 9]).unwrap();
+    let mut out = [0; 2];
+    pool.read(&addr2, &mut out).unwrap();
+    assert_eq!(out, [9, 9]);
+}
+
+#[test]
+fn test_static_pool_free_clears_data() {
+    let mut pool = pool();
+    let addr = pool.add(&[7, 7, 7]).unwrap();
+    pool.free(addr);
+
+    let addr2 = pool.add(&[1]).unwrap();
+    let mut out = [9; 4];
+    pool.read(&addr2, &mut out).unwrap();
+    assert_eq!(out, [1, 0, 0, 0]);
+}
@@ -0,0 +1,60 @@
+use ry26::SyncDoubleBuffer;
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn test_sync_double_buffer_starts_empty() {
+    let buffer: SyncDoubleBuffer<i32> = SyncDoubleBuffer::new(4);
+    assert!(buffer.front().is_empty());
+}
+
+#[test]
+fn test_sync_double_buffer_swap_publishes_back_as_front() {
+    let buffer: SyncDoubleBuffer<i32> = SyncDoubleBuffer::new(4);
+    buffer.with_back_mut(|back| {
+        back.push(1);
+        back.push(2);
+    });
+    buffer.swap();
+
+    assert_eq!(*buffer.front(), vec![1, 2]);
+}
+
+#[test]
+fn test_sync_double_buffer_front_snapshot_survives_next_swap() {
+    let buffer: SyncDoubleBuffer<i32> = SyncDoubleBuffer::new(4);
+    buffer.with_back_mut(|back| back.push(1));
+    buffer.swap();
+    let snapshot = buffer.front();
+
+    buffer.with_back_mut(|back| back.push(2));
+    buffer.swap();
+
+    assert_eq!(*snapshot, vec![1]);
+    assert_eq!(*buffer.front(), vec![2]);
+}
+
+#[test]
+fn test_sync_double_buffer_reader_on_other_thread() {
+    let buffer: Arc<SyncDoubleBuffer<i32>> = Arc::new(SyncDoubleBuffer::new(4));
+    buffer.with_back_mut(|back| back.push(42));
+    buffer.swap();
+
+    let reader_buffer = Arc::clone(&buffer);
+    let reader = thread::spawn(move || reader_buffer.front());
+    assert_eq!(*reader.join().unwrap(), vec![42]);
+}
+
+#[test]
+fn test_sync_double_buffer_recycles_retired_front() {
+    let buffer: SyncDoubleBuffer<i32> = SyncDoubleBuffer::new(4);
+    buffer.with_back_mut(|back| back.push(1));
+    buffer.swap();
+    // No reader is holding the retired front, so it should have been recycled.
+    buffer.with_back_mut(|back| back.push(2));
+    buffer.swap();
+    buffer.with_back_mut(|back| back.push(3));
+    buffer.swap();
+
+    assert_eq!(*buffer.front(), vec![3]);
+}